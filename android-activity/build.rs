@@ -1,5 +1,6 @@
+#![allow(dead_code)]
 
-fn main() {
+fn build_glue_for_game_activity() {
     cc::Build::new()
         .cpp(true)
         .include("csrc")
@@ -12,10 +13,18 @@ fn main() {
         .file("csrc/game-text-input/gametextinput.cpp")
         .cpp_link_stdlib("c++_static")
         .compile("libgame_text_input.a");
-    cc::Build::new()
-        .include("csrc")
+    let mut glue = cc::Build::new();
+    glue.include("csrc")
         .include("csrc/game-activity/native_app_glue")
         .file("csrc/game-activity/native_app_glue/android_native_app_glue.c")
-        .cpp_link_stdlib("c++_static")
-        .compile("libnative_app_glue.a");
-}
\ No newline at end of file
+        .cpp_link_stdlib("c++_static");
+    if cfg!(feature = "atrace") {
+        glue.define("ANDROID_ACTIVITY_ATRACE", None);
+    }
+    glue.compile("libnative_app_glue.a");
+}
+
+fn main() {
+    #[cfg(feature = "game-activity")]
+    build_glue_for_game_activity();
+}
@@ -38,6 +38,21 @@
 #define LOGV(...) ((void)0)
 #endif
 
+/* With the `atrace` feature, each Activity callback is traced as a section
+ * that ends when the callback returns */
+#ifdef ANDROID_ACTIVITY_ATRACE
+static void trace_callback_end(bool* begun) {
+    if (*begun) {
+        _rust_glue_trace_end();
+    }
+}
+#define TRACE_CALLBACK(name)                                                \
+    bool trace_callback_begun __attribute__((cleanup(trace_callback_end))) = \
+        _rust_glue_trace_begin(name)
+#else
+#define TRACE_CALLBACK(name) ((void)0)
+#endif
+
 static void free_saved_state(struct android_app* android_app) {
     pthread_mutex_lock(&android_app->mutex);
     if (android_app->savedState != NULL) {
@@ -271,6 +286,7 @@ static struct android_app* android_app_create(GameActivity* activity,
 
     android_app->keyEventFilter = default_key_filter;
     android_app->motionEventFilter = default_motion_filter;
+    android_app->rustInputBuffer = _rust_glue_create_input_buffer();
 
     LOGV("Launching android_app_entry in a thread");
     pthread_attr_t attr;
@@ -280,7 +296,7 @@ static struct android_app* android_app_create(GameActivity* activity,
 
     // Wait for thread to start.
     pthread_mutex_lock(&android_app->mutex);
-    while (!android_app->running) {
+    while (!android_app->running && !android_app->destroyed) {
         pthread_cond_wait(&android_app->cond, &android_app->mutex);
     }
     pthread_mutex_unlock(&android_app->mutex);
@@ -294,6 +310,34 @@ static void android_app_write_cmd(struct android_app* android_app, int8_t cmd) {
     }
 }
 
+/**
+ * Some commands are followed by a payload on the command pipe, which the Rust
+ * glue layer reads right after the command:
+ *
+ * - APP_CMD_WINDOW_RESIZED: the new width and height (int32_t[2])
+ * - APP_CMD_LOW_MEMORY: the trim memory level (int32_t)
+ * - APP_CMD_WINDOW_INSETS_CHANGED: the insets for each GameCommonInsetsType
+ *   (ARect[GAMECOMMON_INSETS_TYPE_COUNT])
+ *
+ * The command and its payload are written with a single write() so the
+ * payload is always available once the command has been read.
+ */
+static void android_app_write_cmd_payload(struct android_app* android_app,
+                                          int8_t cmd, const void* payload,
+                                          size_t size) {
+    char buf[sizeof(cmd) + sizeof(ARect) * GAMECOMMON_INSETS_TYPE_COUNT];
+    if (size > sizeof(buf) - sizeof(cmd)) {
+        LOGE("Payload for android_app cmd %d is too large", cmd);
+        return;
+    }
+    memcpy(buf, &cmd, sizeof(cmd));
+    memcpy(buf + sizeof(cmd), payload, size);
+    if (write(android_app->msgwrite, buf, sizeof(cmd) + size) !=
+        (ssize_t)(sizeof(cmd) + size)) {
+        LOGE("Failure writing android_app cmd: %s", strerror(errno));
+    }
+}
+
 static void android_app_set_window(struct android_app* android_app,
                                    ANativeWindow* window) {
     LOGV("android_app_set_window called");
@@ -305,7 +349,8 @@ static void android_app_set_window(struct android_app* android_app,
     if (window != NULL) {
         android_app_write_cmd(android_app, APP_CMD_INIT_WINDOW);
     }
-    while (android_app->window != android_app->pendingWindow) {
+    while (android_app->window != android_app->pendingWindow &&
+           !android_app->destroyed) {
         pthread_cond_wait(&android_app->cond, &android_app->mutex);
     }
     pthread_mutex_unlock(&android_app->mutex);
@@ -315,7 +360,7 @@ static void android_app_set_activity_state(struct android_app* android_app,
                                            int8_t cmd) {
     pthread_mutex_lock(&android_app->mutex);
     android_app_write_cmd(android_app, cmd);
-    while (android_app->activityState != cmd) {
+    while (android_app->activityState != cmd && !android_app->destroyed) {
         pthread_cond_wait(&android_app->cond, &android_app->mutex);
     }
     pthread_mutex_unlock(&android_app->mutex);
@@ -331,6 +376,7 @@ static void android_app_free(struct android_app* android_app) {
 
     close(android_app->msgread);
     close(android_app->msgwrite);
+    _rust_glue_free_input_buffer(android_app->rustInputBuffer);
     pthread_cond_destroy(&android_app->cond);
     pthread_mutex_destroy(&android_app->mutex);
     free(android_app);
@@ -341,16 +387,19 @@ static inline struct android_app* ToApp(GameActivity* activity) {
 }
 
 static void onDestroy(GameActivity* activity) {
+    TRACE_CALLBACK("onDestroy");
     LOGV("Destroy: %p", activity);
     android_app_free(ToApp(activity));
 }
 
 static void onStart(GameActivity* activity) {
+    TRACE_CALLBACK("onStart");
     LOGV("Start: %p", activity);
     android_app_set_activity_state(ToApp(activity), APP_CMD_START);
 }
 
 static void onResume(GameActivity* activity) {
+    TRACE_CALLBACK("onResume");
     LOGV("Resume: %p", activity);
     android_app_set_activity_state(ToApp(activity), APP_CMD_RESUME);
 }
@@ -358,6 +407,7 @@ static void onResume(GameActivity* activity) {
 static void onSaveInstanceState(GameActivity* activity,
                                 SaveInstanceStateRecallback recallback,
                                 void* context) {
+    TRACE_CALLBACK("onSaveInstanceState");
     LOGV("SaveInstanceState: %p", activity);
 
     struct android_app* android_app = ToApp(activity);
@@ -365,7 +415,7 @@ static void onSaveInstanceState(GameActivity* activity,
     pthread_mutex_lock(&android_app->mutex);
     android_app->stateSaved = 0;
     android_app_write_cmd(android_app, APP_CMD_SAVE_STATE);
-    while (!android_app->stateSaved) {
+    while (!android_app->stateSaved && !android_app->destroyed) {
         pthread_cond_wait(&android_app->cond, &android_app->mutex);
     }
 
@@ -383,26 +433,33 @@ static void onSaveInstanceState(GameActivity* activity,
 }
 
 static void onPause(GameActivity* activity) {
+    TRACE_CALLBACK("onPause");
     LOGV("Pause: %p", activity);
     android_app_set_activity_state(ToApp(activity), APP_CMD_PAUSE);
 }
 
 static void onStop(GameActivity* activity) {
+    TRACE_CALLBACK("onStop");
     LOGV("Stop: %p", activity);
     android_app_set_activity_state(ToApp(activity), APP_CMD_STOP);
 }
 
 static void onConfigurationChanged(GameActivity* activity) {
+    TRACE_CALLBACK("onConfigurationChanged");
     LOGV("ConfigurationChanged: %p", activity);
     android_app_write_cmd(ToApp(activity), APP_CMD_CONFIG_CHANGED);
 }
 
 static void onTrimMemory(GameActivity* activity, int level) {
+    TRACE_CALLBACK("onTrimMemory");
     LOGV("TrimMemory: %p %d", activity, level);
-    android_app_write_cmd(ToApp(activity), APP_CMD_LOW_MEMORY);
+    int32_t payload = level;
+    android_app_write_cmd_payload(ToApp(activity), APP_CMD_LOW_MEMORY, &payload,
+                                  sizeof(payload));
 }
 
 static void onWindowFocusChanged(GameActivity* activity, bool focused) {
+    TRACE_CALLBACK("onWindowFocusChanged");
     LOGV("WindowFocusChanged: %p -- %d", activity, focused);
     android_app_write_cmd(ToApp(activity),
                           focused ? APP_CMD_GAINED_FOCUS : APP_CMD_LOST_FOCUS);
@@ -410,27 +467,33 @@ static void onWindowFocusChanged(GameActivity* activity, bool focused) {
 
 static void onNativeWindowCreated(GameActivity* activity,
                                   ANativeWindow* window) {
+    TRACE_CALLBACK("onNativeWindowCreated");
     LOGV("NativeWindowCreated: %p -- %p", activity, window);
     android_app_set_window(ToApp(activity), window);
 }
 
 static void onNativeWindowDestroyed(GameActivity* activity,
                                     ANativeWindow* window) {
+    TRACE_CALLBACK("onNativeWindowDestroyed");
     LOGV("NativeWindowDestroyed: %p -- %p", activity, window);
     android_app_set_window(ToApp(activity), NULL);
 }
 
 static void onNativeWindowRedrawNeeded(GameActivity* activity,
                                        ANativeWindow* window) {
+    TRACE_CALLBACK("onNativeWindowRedrawNeeded");
     LOGV("NativeWindowRedrawNeeded: %p -- %p", activity, window);
     android_app_write_cmd(ToApp(activity), APP_CMD_WINDOW_REDRAW_NEEDED);
 }
 
 static void onNativeWindowResized(GameActivity* activity, ANativeWindow* window,
                                   int32_t width, int32_t height) {
+    TRACE_CALLBACK("onNativeWindowResized");
     LOGV("NativeWindowResized: %p -- %p ( %d x %d )", activity, window, width,
          height);
-    android_app_write_cmd(ToApp(activity), APP_CMD_WINDOW_RESIZED);
+    int32_t payload[2] = {width, height};
+    android_app_write_cmd_payload(ToApp(activity), APP_CMD_WINDOW_RESIZED,
+                                  payload, sizeof(payload));
 }
 
 void android_app_set_motion_event_filter(struct android_app* app,
@@ -444,6 +507,7 @@ static bool onTouchEvent(GameActivity* activity,
                          const GameActivityMotionEvent* event,
                          const GameActivityHistoricalPointerAxes* historical,
                          int historicalLen) {
+    TRACE_CALLBACK("onTouchEvent");
     struct android_app* android_app = ToApp(activity);
     pthread_mutex_lock(&android_app->mutex);
 
@@ -453,31 +517,10 @@ static bool onTouchEvent(GameActivity* activity,
         return false;
     }
 
-    struct android_input_buffer* inputBuffer =
-        &android_app->inputBuffers[android_app->currentInputBuffer];
+    // Add to the list of active motion events (historical samples are
+    // dropped, see `_rust_glue_create_input_buffer()`)
+    _rust_glue_push_motion_event(android_app->rustInputBuffer, event);
 
-    // Add to the list of active motion events
-    if (inputBuffer->motionEventsCount <
-        NATIVE_APP_GLUE_MAX_NUM_MOTION_EVENTS) {
-        int new_ix = inputBuffer->motionEventsCount;
-        memcpy(&inputBuffer->motionEvents[new_ix], event,
-               sizeof(GameActivityMotionEvent));
-        ++inputBuffer->motionEventsCount;
-
-        if (inputBuffer->historicalSamplesCount + historicalLen <=
-            NATIVE_APP_GLUE_MAX_HISTORICAL_POINTER_SAMPLES) {
-
-            int start_ix = inputBuffer->historicalSamplesCount;
-            memcpy(&inputBuffer->historicalAxisSamples[start_ix], historical,
-                    sizeof(historical[0]) * historicalLen);
-            inputBuffer->historicalSamplesCount += event->historicalCount;
-
-            inputBuffer->motionEvents[new_ix].historicalStart = start_ix;
-            inputBuffer->motionEvents[new_ix].historicalCount = historicalLen;
-        } else {
-            inputBuffer->motionEvents[new_ix].historicalCount = 0;
-        }
-    }
     pthread_mutex_unlock(&android_app->mutex);
     return true;
 }
@@ -515,6 +558,7 @@ void android_app_set_key_event_filter(struct android_app* app,
 }
 
 static bool onKey(GameActivity* activity, const GameActivityKeyEvent* event) {
+    TRACE_CALLBACK("onKey");
     struct android_app* android_app = ToApp(activity);
     pthread_mutex_lock(&android_app->mutex);
 
@@ -524,16 +568,8 @@ static bool onKey(GameActivity* activity, const GameActivityKeyEvent* event) {
         return false;
     }
 
-    struct android_input_buffer* inputBuffer =
-        &android_app->inputBuffers[android_app->currentInputBuffer];
-
     // Add to the list of active key down events
-    if (inputBuffer->keyEventsCount < NATIVE_APP_GLUE_MAX_NUM_KEY_EVENTS) {
-        int new_ix = inputBuffer->keyEventsCount;
-        memcpy(&inputBuffer->keyEvents[new_ix], event,
-               sizeof(GameActivityKeyEvent));
-        ++inputBuffer->keyEventsCount;
-    }
+    _rust_glue_push_key_event(android_app->rustInputBuffer, event);
 
     pthread_mutex_unlock(&android_app->mutex);
     return true;
@@ -545,6 +581,7 @@ void android_app_clear_key_events(struct android_input_buffer* inputBuffer) {
 
 static void onTextInputEvent(GameActivity* activity,
                              const GameTextInputState* state) {
+    TRACE_CALLBACK("onTextInputEvent");
     struct android_app* android_app = ToApp(activity);
     pthread_mutex_lock(&android_app->mutex);
 
@@ -553,8 +590,16 @@ static void onTextInputEvent(GameActivity* activity,
 }
 
 static void onWindowInsetsChanged(GameActivity* activity) {
+    TRACE_CALLBACK("onWindowInsetsChanged");
     LOGV("WindowInsetsChanged: %p", activity);
-    android_app_write_cmd(ToApp(activity), APP_CMD_WINDOW_INSETS_CHANGED);
+    ARect payload[GAMECOMMON_INSETS_TYPE_COUNT];
+    for (int type = 0; type < GAMECOMMON_INSETS_TYPE_COUNT; ++type) {
+        GameActivity_getWindowInsets(activity, (GameCommonInsetsType)type,
+                                     &payload[type]);
+    }
+    android_app_write_cmd_payload(ToApp(activity),
+                                  APP_CMD_WINDOW_INSETS_CHANGED, payload,
+                                  sizeof(payload));
 }
 
 JNIEXPORT
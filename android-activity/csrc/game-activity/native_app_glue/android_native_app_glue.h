@@ -295,6 +295,11 @@ struct android_app {
     android_key_event_filter keyEventFilter;
     android_motion_event_filter motionEventFilter;
 
+    /**
+     * The Rust glue layer's input buffer, see `_rust_glue_create_input_buffer()`
+     */
+    void* rustInputBuffer;
+
     /** @endcond */
 };
 
@@ -485,6 +490,34 @@ void android_app_clear_key_events(struct android_input_buffer* inputBuffer);
  */
 extern void _rust_glue_entry(struct android_app* app);
 
+/**
+ * Input events are buffered by the Rust glue layer, instead of in
+ * `inputBuffers`, so that the number of events isn't limited by
+ * NATIVE_APP_GLUE_MAX_NUM_MOTION_EVENTS or NATIVE_APP_GLUE_MAX_NUM_KEY_EVENTS.
+ *
+ * The buffer is created before the application thread is started and stored
+ * in `rustInputBuffer`, leaving `userData` free for the application.
+ *
+ * Historical samples aren't passed on, since they aren't exposed by the Rust
+ * API (and GameActivity only records them for the axes that are enabled via
+ * `GameActivityHistoricalPointerAxes_enableAxis()`).
+ */
+extern void* _rust_glue_create_input_buffer(void);
+extern void _rust_glue_free_input_buffer(void* buffer);
+extern void _rust_glue_push_motion_event(void* buffer,
+                                         const GameActivityMotionEvent* event);
+extern void _rust_glue_push_key_event(void* buffer,
+                                      const GameActivityKeyEvent* event);
+
+/**
+ * With the `atrace` feature, the Activity callbacks are traced via the Rust
+ * glue layer's `trace` module. `_rust_glue_trace_begin()` returns whether a
+ * section was begun (i.e. whether tracing is enabled), in which case it must
+ * be ended by `_rust_glue_trace_end()` on the same thread.
+ */
+extern bool _rust_glue_trace_begin(const char* name);
+extern void _rust_glue_trace_end(void);
+
 /**
  * Set the filter to use when processing key events.
  * Any events for which the filter returns false will be ignored by
@@ -8,7 +8,7 @@ fi
 while read ARCH && read TARGET ; do
 
     # --module-raw-line 'use '
-    bindgen wrapper.h -o src/ffi_$ARCH.rs \
+    bindgen game-activity-wrapper.h -o src/game_activity/ffi_$ARCH.rs \
         --blocklist-item 'JNI\w+' \
         --blocklist-item 'C?_?JNIEnv' \
         --blocklist-item '_?JavaVM' \
@@ -0,0 +1,491 @@
+//! A virtual filesystem over the application's assets
+//!
+//! Asset paths are always relative to the `assets/` directory of the `.apk` and
+//! use `/` as a separator, so for example `"shaders/main.wgsl"`. A leading `/`
+//! or `./` is ignored but `..` components aren't supported.
+//!
+//! On a device, [`Assets`] is backed by the Activity's `AAssetManager`. With the
+//! `test` backend (or when created via [`Assets::from_dir()`]) the same paths are
+//! instead served from a local directory, so code that loads assets can also be
+//! tested on the host.
+//!
+//! Note: directories are listed via the Java `AssetManager.list()` method, since
+//! an `AAssetDir` only reports the files within a directory. Any other entries
+//! are the subdirectories.
+
+use std::fs::{self, File};
+use std::future::Future;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Deref;
+use std::os::unix::prelude::*;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::{ptr, slice, thread};
+
+#[cfg(not(feature = "test"))]
+use {
+    ndk::asset::{Asset, AssetManager},
+    std::ffi::CString,
+};
+
+#[derive(Debug, Clone)]
+enum Source {
+    #[cfg(not(feature = "test"))]
+    Manager(Arc<AssetManager>),
+    Dir(PathBuf),
+}
+
+/// The application's assets, as returned by [`AndroidApp::assets()`](crate::AndroidApp::assets)
+///
+/// `Assets` can be cloned and sent to other threads, such as for loading assets
+/// in the background via [`Assets::load()`].
+#[derive(Debug, Clone)]
+pub struct Assets {
+    source: Source,
+}
+
+/// An entry within an asset directory, as returned by [`Assets::read_dir()`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetEntry {
+    /// The full path of the entry
+    pub path: String,
+    pub is_dir: bool,
+}
+
+// Strips any leading `/` or `./` and rejects paths that would escape the assets
+fn normalize(path: &str) -> io::Result<String> {
+    let mut components = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Unsupported asset path {path:?}"))),
+            component => components.push(component),
+        }
+    }
+    Ok(components.join("/"))
+}
+
+fn not_found(path: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("No asset at {path:?}"))
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{dir}/{name}")
+    }
+}
+
+impl Assets {
+    #[cfg(not(feature = "test"))]
+    pub(crate) fn from_asset_manager(manager: AssetManager) -> Self {
+        Self { source: Source::Manager(Arc::new(manager)) }
+    }
+
+    /// Serves assets from a local directory instead of an `AAssetManager`
+    ///
+    /// This is how assets are provided with the `test` backend, but can also be
+    /// used to load assets from a directory on a device.
+    pub fn from_dir(dir: impl Into<PathBuf>) -> Self {
+        Self { source: Source::Dir(dir.into()) }
+    }
+
+    /// Opens the asset at `path` for reading
+    pub fn open(&self, path: &str) -> io::Result<AssetFile> {
+        let path = normalize(path)?;
+        match &self.source {
+            #[cfg(not(feature = "test"))]
+            Source::Manager(manager) => {
+                let name = CString::new(path.as_str()).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+                let asset = manager.open(&name).ok_or_else(|| not_found(&path))?;
+                Ok(AssetFile { inner: AssetFileInner::Asset(asset) })
+            }
+            Source::Dir(dir) => {
+                let file = File::open(dir.join(&path))?;
+                if file.metadata()?.is_dir() {
+                    return Err(not_found(&path));
+                }
+                Ok(AssetFile { inner: AssetFileInner::File(file) })
+            }
+        }
+    }
+
+    /// Reads the whole asset at `path`
+    pub fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        let mut file = self.open(path)?;
+        let mut buf = Vec::with_capacity(file.len() as usize);
+        file.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Memory maps the asset at `path`
+    ///
+    /// This is only possible for assets that are stored uncompressed within the
+    /// `.apk` (such as those with a `noCompress` extension), otherwise an
+    /// error of kind [`io::ErrorKind::Unsupported`] is returned and the asset
+    /// can be read via [`Assets::read()`] instead.
+    pub fn map(&self, path: &str) -> io::Result<AssetMap> {
+        let file = self.open(path)?;
+        match &file.inner {
+            #[cfg(not(feature = "test"))]
+            AssetFileInner::Asset(asset) => {
+                let (mut start, mut len) = (0, 0);
+                let fd = unsafe { ndk_sys::AAsset_openFileDescriptor64(asset.ptr().as_ptr(), &mut start, &mut len) };
+                if fd < 0 {
+                    return Err(io::Error::new(io::ErrorKind::Unsupported, format!("The asset {path:?} is compressed and can't be mapped")));
+                }
+                let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+                AssetMap::new(fd.as_fd(), start as u64, len as usize)
+            }
+            AssetFileInner::File(file) => AssetMap::new(file.as_fd(), 0, file.metadata()?.len() as usize),
+        }
+    }
+
+    /// Lists the files and directories within the asset directory `dir`
+    ///
+    /// Entries are sorted by path and the root directory is `""`. As with
+    /// `AssetManager.list()` on a device, listing a directory that doesn't exist
+    /// or a file returns no entries, rather than an error.
+    pub fn read_dir(&self, dir: &str) -> io::Result<Vec<AssetEntry>> {
+        let dir = normalize(dir)?;
+        let mut entries = match &self.source {
+            #[cfg(not(feature = "test"))]
+            Source::Manager(manager) => {
+                let names = unsafe { jni::list_assets(&dir)? };
+                if names.is_empty() {
+                    return Ok(Vec::new());
+                }
+                let c_dir = CString::new(dir.as_str()).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+                let files: Vec<CString> = manager.open_dir(&c_dir).map_or_else(Vec::new, |files| files.collect());
+                names.into_iter()
+                    .map(|name| {
+                        let is_dir = !files.iter().any(|file| file.as_bytes() == name.as_bytes());
+                        AssetEntry { path: join(&dir, &name), is_dir }
+                    })
+                    .collect()
+            }
+            Source::Dir(root) => {
+                let path = root.join(&dir);
+                if !path.is_dir() {
+                    return Ok(Vec::new());
+                }
+                let mut entries = Vec::new();
+                for entry in fs::read_dir(path)? {
+                    let entry = entry?;
+                    let name = entry.file_name().into_string()
+                        .map_err(|name| io::Error::new(io::ErrorKind::InvalidData, format!("Non UTF-8 asset name {name:?}")))?;
+                    entries.push(AssetEntry { path: join(&dir, &name), is_dir: entry.file_type()?.is_dir() });
+                }
+                entries
+            }
+        };
+        entries.sort();
+        Ok(entries)
+    }
+
+    /// Recursively lists the paths of all the files within the asset directory `dir`
+    ///
+    /// Paths are sorted and the root directory is `""`.
+    pub fn walk_dir(&self, dir: &str) -> io::Result<Vec<String>> {
+        let mut files = Vec::new();
+        let mut dirs = vec![normalize(dir)?];
+        while let Some(dir) = dirs.pop() {
+            for entry in self.read_dir(&dir)? {
+                if entry.is_dir {
+                    dirs.push(entry.path);
+                } else {
+                    files.push(entry.path);
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Reads the asset at `path` on a background thread
+    ///
+    /// The returned [`AssetLoad`] can either be waited on or polled as a [`Future`].
+    pub fn load(&self, path: &str) -> AssetLoad {
+        let shared = Arc::new(LoadShared::default());
+        let (assets, path) = (self.clone(), path.to_string());
+        {
+            let shared = shared.clone();
+            thread::Builder::new()
+                .name("asset-loader".to_string())
+                .spawn(move || shared.complete(assets.read(&path)))
+                .expect("Failed to spawn asset loader thread");
+        }
+        AssetLoad { shared }
+    }
+}
+
+#[derive(Debug)]
+enum AssetFileInner {
+    #[cfg(not(feature = "test"))]
+    Asset(Asset),
+    File(File),
+}
+
+/// An open asset, as returned by [`Assets::open()`]
+#[derive(Debug)]
+pub struct AssetFile {
+    inner: AssetFileInner,
+}
+
+impl AssetFile {
+    /// The total length of the asset, in bytes
+    pub fn len(&self) -> u64 {
+        match &self.inner {
+            #[cfg(not(feature = "test"))]
+            AssetFileInner::Asset(asset) => asset.get_length() as u64,
+            AssetFileInner::File(file) => file.metadata().map_or(0, |metadata| metadata.len()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Read for AssetFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.inner {
+            #[cfg(not(feature = "test"))]
+            AssetFileInner::Asset(asset) => asset.read(buf),
+            AssetFileInner::File(file) => file.read(buf),
+        }
+    }
+}
+
+impl Seek for AssetFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match &mut self.inner {
+            #[cfg(not(feature = "test"))]
+            AssetFileInner::Asset(asset) => asset.seek(pos),
+            AssetFileInner::File(file) => file.seek(pos),
+        }
+    }
+}
+
+/// A read-only memory mapping of an asset, as returned by [`Assets::map()`]
+#[derive(Debug)]
+pub struct AssetMap {
+    // The page aligned mapping, or null for an empty asset
+    addr: *mut libc::c_void,
+    map_len: usize,
+    offset: usize,
+    len: usize,
+}
+
+// The mapping is read-only and owned by the `AssetMap`
+unsafe impl Send for AssetMap {}
+unsafe impl Sync for AssetMap {}
+
+impl AssetMap {
+    // Maps `len` bytes of `fd` from `start`, which doesn't need to be page aligned
+    // (assets are generally at arbitrary offsets within the `.apk`)
+    fn new(fd: BorrowedFd<'_>, start: u64, len: usize) -> io::Result<Self> {
+        if len == 0 {
+            return Ok(Self { addr: ptr::null_mut(), map_len: 0, offset: 0, len: 0 });
+        }
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+        let aligned_start = start - start % page_size;
+        let offset = (start - aligned_start) as usize;
+        let map_len = offset + len;
+        let addr = unsafe {
+            libc::mmap(ptr::null_mut(), map_len, libc::PROT_READ, libc::MAP_PRIVATE, fd.as_raw_fd(), aligned_start as libc::off_t)
+        };
+        if addr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { addr, map_len, offset, len })
+    }
+}
+
+impl Deref for AssetMap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        if self.addr.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts((self.addr as *const u8).add(self.offset), self.len) }
+        }
+    }
+}
+
+impl AsRef<[u8]> for AssetMap {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Drop for AssetMap {
+    fn drop(&mut self) {
+        if !self.addr.is_null() {
+            unsafe { libc::munmap(self.addr, self.map_len) };
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct LoadState {
+    result: Option<io::Result<Vec<u8>>>,
+    waker: Option<Waker>,
+}
+
+#[derive(Debug, Default)]
+struct LoadShared {
+    state: Mutex<LoadState>,
+    loaded: Condvar,
+}
+
+impl LoadShared {
+    fn complete(&self, result: io::Result<Vec<u8>>) {
+        let mut state = self.state.lock().unwrap();
+        state.result = Some(result);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        self.loaded.notify_all();
+    }
+}
+
+/// An asset that's being read on a background thread, as returned by [`Assets::load()`]
+///
+/// As a [`Future`], this resolves to the asset's contents and can be awaited
+/// from any executor, such as the one provided by the `async` feature.
+#[derive(Debug)]
+pub struct AssetLoad {
+    shared: Arc<LoadShared>,
+}
+
+impl AssetLoad {
+    /// Returns the asset's contents if it has finished loading, otherwise gives
+    /// back the `AssetLoad`
+    pub fn try_take(self) -> Result<io::Result<Vec<u8>>, Self> {
+        let result = self.shared.state.lock().unwrap().result.take();
+        result.ok_or(self)
+    }
+
+    /// Blocks until the asset has finished loading
+    pub fn wait(self) -> io::Result<Vec<u8>> {
+        let state = self.shared.state.lock().unwrap();
+        let mut state = self.shared.loaded.wait_while(state, |state| state.result.is_none()).unwrap();
+        state.result.take().unwrap()
+    }
+}
+
+impl Future for AssetLoad {
+    type Output = io::Result<Vec<u8>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// A minimal binding for `AssetManager.list()`, via the Activity's `getAssets()`
+#[cfg(not(feature = "test"))]
+mod jni {
+    use jni_sys::{jobject, jstring, jvalue, JNIEnv, JavaVM, JNI_EDETACHED, JNI_OK, JNI_VERSION_1_6};
+    use std::ffi::{CStr, CString};
+    use std::io;
+    use std::os::raw::c_void;
+    use std::ptr;
+
+    fn jni_error(what: &str) -> io::Error {
+        io::Error::other(format!("JNI error: {what}"))
+    }
+
+    macro_rules! call {
+        ($env:expr, $fn:ident $(, $arg:expr)*) => {
+            ((**$env).$fn.unwrap())($env $(, $arg)*)
+        };
+    }
+
+    /// Lists the names of the files and directories within the asset directory `dir`
+    ///
+    /// # Safety
+    /// The Android context must have been initialized via `ndk_context`
+    pub(super) unsafe fn list_assets(dir: &str) -> io::Result<Vec<String>> {
+        let context = ndk_context::android_context();
+        let vm = context.vm() as *mut JavaVM;
+        let activity = context.context() as jobject;
+
+        let mut env: *mut JNIEnv = ptr::null_mut();
+        let attached = match ((**vm).GetEnv.unwrap())(vm, &mut env as *mut _ as *mut *mut c_void, JNI_VERSION_1_6) {
+            JNI_OK => false,
+            JNI_EDETACHED => {
+                if ((**vm).AttachCurrentThread.unwrap())(vm, &mut env as *mut _ as *mut *mut c_void, ptr::null_mut()) != JNI_OK {
+                    return Err(jni_error("failed to attach thread"));
+                }
+                true
+            }
+            _ => return Err(jni_error("failed to get JNIEnv")),
+        };
+
+        if call!(env, PushLocalFrame, 16) != JNI_OK {
+            return Err(jni_error("failed to push local frame"));
+        }
+        let result = list_assets_with_env(env, activity, dir);
+        if call!(env, ExceptionCheck) != 0 {
+            call!(env, ExceptionClear);
+        }
+        call!(env, PopLocalFrame, ptr::null_mut());
+
+        if attached {
+            ((**vm).DetachCurrentThread.unwrap())(vm);
+        }
+        result
+    }
+
+    unsafe fn list_assets_with_env(env: *mut JNIEnv, activity: jobject, dir: &str) -> io::Result<Vec<String>> {
+        let activity_class = call!(env, GetObjectClass, activity);
+        let get_assets = call!(env, GetMethodID, activity_class,
+            c"getAssets".as_ptr(), c"()Landroid/content/res/AssetManager;".as_ptr());
+        if get_assets.is_null() {
+            return Err(jni_error("no getAssets() method"));
+        }
+        let asset_manager = call!(env, CallObjectMethodA, activity, get_assets, ptr::null());
+        if asset_manager.is_null() {
+            return Err(jni_error("getAssets() failed"));
+        }
+
+        let asset_manager_class = call!(env, GetObjectClass, asset_manager);
+        let list = call!(env, GetMethodID, asset_manager_class,
+            c"list".as_ptr(), c"(Ljava/lang/String;)[Ljava/lang/String;".as_ptr());
+        if list.is_null() {
+            return Err(jni_error("no AssetManager.list() method"));
+        }
+        let dir = CString::new(dir).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        let dir = call!(env, NewStringUTF, dir.as_ptr());
+        let names = call!(env, CallObjectMethodA, asset_manager, list, [jvalue { l: dir }].as_ptr());
+        if call!(env, ExceptionCheck) != 0 || names.is_null() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "AssetManager.list() failed"));
+        }
+
+        let len = call!(env, GetArrayLength, names);
+        let mut result = Vec::with_capacity(len as usize);
+        for i in 0..len {
+            let name: jstring = call!(env, GetObjectArrayElement, names, i);
+            let chars = call!(env, GetStringUTFChars, name, ptr::null_mut());
+            if chars.is_null() {
+                return Err(jni_error("failed to get string"));
+            }
+            result.push(CStr::from_ptr(chars).to_string_lossy().into_owned());
+            call!(env, ReleaseStringUTFChars, name, chars);
+            call!(env, DeleteLocalRef, name);
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,444 @@
+//! An `async` interface for handling application events
+//!
+//! [`AndroidApp::events()`] returns a [`Stream`] of owned [`Event`]s that can be
+//! consumed from `async` code, and [`Executor`] is a small single-threaded executor
+//! that runs futures on the `android_main` thread while waiting for events from
+//! the application's looper.
+//!
+//! The stream is only driven by polling the application's looper, which is done by
+//! the [`Executor`] whenever all of its futures are blocked, so [`EventStream`]
+//! should not be used with other executors. Similarly, calling
+//! [`AndroidApp::poll_events()`] directly while an [`EventStream`] is in use will
+//! steal events from the stream.
+//!
+//! Some events need to be synchronized with the Java main thread, which is blocked
+//! until the application has finished handling them. With `poll_events()` this
+//! happens when the callback returns, while the stream instead hands out a
+//! [`CommandCompletion`] for [`Event::TerminateWindow`] and [`Event::SaveState`]
+//! which completes the event when it's dropped. No further commands from the
+//! Java main thread are handled until then, although other events (such as
+//! from registered fds and timers) are still delivered. Holding on to a
+//! completion for too long will block the Java main thread and can lead to an
+//! ANR.
+//!
+//! ```no_run
+//! use android_activity::AndroidApp;
+//! use android_activity::async_events::{Event, Executor};
+//! use futures::StreamExt;
+//!
+//! android_activity::main!(android_main);
+//!
+//! fn android_main(app: AndroidApp) {
+//!     let executor = Executor::new(&app);
+//!     executor.block_on(async {
+//!         let mut events = app.events();
+//!         while let Some(event) = events.next().await {
+//!             match event {
+//!                 Event::TerminateWindow(completion) => {
+//!                     // Stop using the window before it's destroyed
+//!                     completion.complete();
+//!                 }
+//!                 Event::SaveState(request) => request.store(b"state"),
+//!                 _ => {}
+//!             }
+//!         }
+//!     });
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+use futures_core::Stream;
+use ndk::configuration::Configuration;
+use ndk::looper::FdEvent;
+
+use crate::{activity_impl, AndroidApp, AndroidAppWaker, ConfigurationDiff, FdToken, MainEvent, PollEvent, Rect, Size, TimerId, TrimMemoryLevel, UserEvent, WindowInsets};
+use crate::poll::GlueBackend;
+
+// Events are read from the looper on the `android_main` thread and then
+// buffered until they are taken from the `EventStream`
+#[derive(Default)]
+struct EventQueue {
+    events: VecDeque<Event>,
+    waker: Option<Waker>,
+}
+
+thread_local! {
+    static EVENT_QUEUE: RefCell<EventQueue> = RefCell::new(EventQueue::default());
+}
+
+/// An owned version of [`PollEvent`] and [`MainEvent`] that's delivered via an
+/// [`EventStream`]
+///
+/// Unlike [`AndroidApp::poll_events()`], wake ups and timeouts aren't reported
+/// as events.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Event {
+    /// See [`MainEvent::InitWindow`]
+    InitWindow,
+
+    /// See [`MainEvent::TerminateWindow`]
+    ///
+    /// The window remains valid until the [`CommandCompletion`] is dropped
+    TerminateWindow(CommandCompletion),
+
+    /// See [`MainEvent::WindowResized`]
+    WindowResized { old_size: Size, new_size: Size },
+
+    /// See [`MainEvent::RedrawNeeded`]
+    RedrawNeeded,
+
+    /// See [`MainEvent::ContentRectChanged`]
+    ContentRectChanged { rect: Rect },
+
+    /// See [`MainEvent::GainedFocus`]
+    GainedFocus,
+
+    /// See [`MainEvent::LostFocus`]
+    LostFocus,
+
+    /// See [`MainEvent::ConfigChanged`]
+    ConfigChanged { diff: ConfigurationDiff, old: Configuration, new: Configuration },
+
+    /// See [`MainEvent::LowMemory`]
+    LowMemory { level: TrimMemoryLevel },
+
+    /// See [`MainEvent::Start`]
+    Start,
+
+    /// See [`MainEvent::Resume`]
+    ///
+    /// Any saved state has already been loaded, since it's only available while
+    /// the event is being handled
+    Resume { saved_state: Option<Vec<u8>> },
+
+    /// See [`MainEvent::SaveState`]
+    SaveState(SaveStateRequest),
+
+    /// See [`MainEvent::Pause`]
+    Pause,
+
+    /// See [`MainEvent::Stop`]
+    Stop,
+
+    /// See [`MainEvent::Destroy`]
+    ///
+    /// This is the last event delivered by an [`EventStream`]
+    Destroy,
+
+    /// See [`MainEvent::InsetsChanged`]
+    InsetsChanged { insets: WindowInsets },
+
+    /// See [`PollEvent::Fd`]
+    Fd { token: FdToken, events: FdEvent },
+
+    /// See [`PollEvent::Timer`]
+    Timer(TimerId),
+
+    /// See [`PollEvent::User`]
+    User(UserEvent),
+
+    /// See [`PollEvent::Frame`]
+    Frame { frame_time_nanos: i64, vsync_id: Option<i64> },
+}
+
+/// Delays the synchronization with the Java main thread that's needed after
+/// handling some events, until the completion is dropped
+///
+/// This must be dropped on the `android_main` thread, which is enforced by
+/// it not being [`Send`].
+#[derive(Debug)]
+pub struct CommandCompletion {
+    app: AndroidApp,
+    // `None` for replayed events (see `AndroidApp::start_replay()`)
+    cmd: Option<i8>,
+}
+
+impl CommandCompletion {
+    /// Notifies the Java main thread that the application has finished handling
+    /// the event
+    ///
+    /// This is equivalent to dropping the completion
+    pub fn complete(self) {}
+}
+
+impl Drop for CommandCompletion {
+    fn drop(&mut self) {
+        if let Some(cmd) = self.cmd {
+            self.app.inner.complete_deferred_cmd(cmd);
+        }
+    }
+}
+
+/// A request for the application to save its state, which is delivered via
+/// [`Event::SaveState`]
+///
+/// The state must be stored before the request is dropped.
+#[derive(Debug)]
+pub struct SaveStateRequest {
+    completion: CommandCompletion,
+}
+
+impl SaveStateRequest {
+    /// See [`StateSaver::store()`](crate::StateSaver::store)
+    pub fn store(&self, state: &[u8]) {
+        let saver = match self.completion.cmd {
+            Some(_) => crate::StateSaver::new(activity_impl::StateSaver { app: &self.completion.app.inner }),
+            None => crate::StateSaver::replayed(),
+        };
+        saver.store(state);
+    }
+
+    /// Notifies the Java main thread that the state has been saved
+    ///
+    /// This is equivalent to dropping the request
+    pub fn complete(self) {}
+}
+
+// Converts an event from the looper into an owned event and adds it to the queue
+// for the `EventStream`
+//
+// This must be called from within the `poll_events()` callback so that the
+// completion of events can be deferred
+fn queue_event(app: &AndroidApp, event: PollEvent) {
+    let take_completion = || CommandCompletion { app: app.clone(), cmd: app.inner.take_cmd_completion() };
+
+    let event = match event {
+        PollEvent::Main(main_event) => match main_event {
+            MainEvent::InitWindow { .. } => Event::InitWindow,
+            MainEvent::TerminateWindow { .. } => Event::TerminateWindow(take_completion()),
+            MainEvent::WindowResized { old_size, new_size } => Event::WindowResized { old_size, new_size },
+            MainEvent::RedrawNeeded { .. } => Event::RedrawNeeded,
+            MainEvent::ContentRectChanged { rect } => Event::ContentRectChanged { rect },
+            MainEvent::GainedFocus => Event::GainedFocus,
+            MainEvent::LostFocus => Event::LostFocus,
+            MainEvent::ConfigChanged { diff, old, new } => Event::ConfigChanged { diff, old, new },
+            MainEvent::LowMemory { level } => Event::LowMemory { level },
+            MainEvent::Start => Event::Start,
+            MainEvent::Resume { loader } => Event::Resume { saved_state: loader.load() },
+            MainEvent::SaveState { .. } => Event::SaveState(SaveStateRequest { completion: take_completion() }),
+            MainEvent::Pause => Event::Pause,
+            MainEvent::Stop => Event::Stop,
+            MainEvent::Destroy => Event::Destroy,
+            MainEvent::InsetsChanged { insets } => Event::InsetsChanged { insets },
+        },
+        PollEvent::Fd { token, events } => Event::Fd { token, events },
+        PollEvent::Timer(id) => Event::Timer(id),
+        PollEvent::User(event) => Event::User(event),
+        PollEvent::Frame { frame_time_nanos, vsync_id } => Event::Frame { frame_time_nanos, vsync_id },
+        PollEvent::Wake | PollEvent::Timeout | PollEvent::Error => return,
+    };
+
+    EVENT_QUEUE.with(|queue| {
+        let mut queue = queue.borrow_mut();
+        queue.events.push_back(event);
+        if let Some(waker) = queue.waker.take() {
+            waker.wake();
+        }
+    });
+}
+
+// Called once `android_main` returns, so that any outstanding completions are
+// dropped while the app is still valid
+pub(crate) fn clear_event_queue() {
+    let events = EVENT_QUEUE.with(|queue| mem::take(&mut *queue.borrow_mut()));
+    drop(events);
+}
+
+/// A [`Stream`] of application events, created via [`AndroidApp::events()`]
+///
+/// The stream ends after delivering [`Event::Destroy`].
+#[derive(Debug)]
+pub struct EventStream {
+    app: AndroidApp,
+    destroyed: bool,
+}
+
+impl EventStream {
+    pub(crate) fn new(app: AndroidApp) -> Self {
+        Self { app, destroyed: false }
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        let event = EVENT_QUEUE.with(|queue| queue.borrow_mut().events.pop_front());
+        if let Some(Event::Destroy) = event {
+            self.destroyed = true;
+        }
+        event
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        let this = self.get_mut();
+        if this.destroyed {
+            return Poll::Ready(None);
+        }
+        if let Some(event) = this.pop_event() {
+            return Poll::Ready(Some(event));
+        }
+
+        // Check for a pending event without blocking. This only polls the looper
+        // once, since a registered fd that stays readable would never let a loop
+        // see a timeout. Otherwise the stream waits until the executor is idle
+        // and blocks on the looper, which queues any further events and wakes
+        // the stream.
+        let app = this.app.clone();
+        app.poll_events(Some(Duration::ZERO), |event| queue_event(&app, event));
+
+        match this.pop_event() {
+            Some(event) => Poll::Ready(Some(event)),
+            None => {
+                EVENT_QUEUE.with(|queue| queue.borrow_mut().waker = Some(cx.waker().clone()));
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// Tracks which futures need to be polled again; woken from any thread
+struct ReadyQueue {
+    main_woken: AtomicBool,
+    tasks: Mutex<Vec<usize>>,
+    looper: AndroidAppWaker,
+
+    // The `android_main` thread, which checks the queue before it blocks on the
+    // looper, so wakes from that thread don't need to wake the looper
+    thread: ThreadId,
+}
+
+struct TaskWaker {
+    // `None` for the future passed to `block_on()`
+    task: Option<usize>,
+    queue: Arc<ReadyQueue>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        match self.task {
+            Some(task) => self.queue.tasks.lock().unwrap().push(task),
+            None => self.queue.main_woken.store(true, Ordering::SeqCst),
+        }
+        if thread::current().id() != self.queue.thread {
+            self.queue.looper.wake();
+        }
+    }
+}
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A single-threaded executor that runs futures on the `android_main` thread
+///
+/// While all futures are blocked the executor waits for events from the
+/// application's looper, which are delivered to the application's
+/// [`EventStream`]. Futures are woken via [`AndroidAppWaker`], so they can be
+/// woken from any thread.
+pub struct Executor {
+    app: AndroidApp,
+    queue: Arc<ReadyQueue>,
+    tasks: RefCell<Vec<Option<Task>>>,
+
+    // Tasks that were spawned while other tasks were being polled
+    spawned: RefCell<Vec<Task>>,
+}
+
+impl Executor {
+    pub fn new(app: &AndroidApp) -> Self {
+        Self {
+            app: app.clone(),
+            queue: Arc::new(ReadyQueue {
+                main_woken: AtomicBool::new(false),
+                tasks: Mutex::new(Vec::new()),
+                looper: app.create_waker(),
+                thread: thread::current().id(),
+            }),
+            tasks: RefCell::new(Vec::new()),
+            spawned: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Spawns a future that will be polled by [`Executor::block_on()`]
+    ///
+    /// Any spawned futures that haven't completed when `block_on()` returns will
+    /// continue to make progress the next time `block_on()` is called.
+    pub fn spawn<F>(&self, future: F)
+        where F: Future<Output = ()> + 'static
+    {
+        self.spawned.borrow_mut().push(Box::pin(future));
+    }
+
+    /// Runs `future`, along with any spawned futures, until `future` completes
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(TaskWaker { task: None, queue: self.queue.clone() }));
+        self.queue.main_woken.store(true, Ordering::SeqCst);
+
+        loop {
+            if self.queue.main_woken.swap(false, Ordering::SeqCst) {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+                    return output;
+                }
+            }
+
+            self.poll_tasks();
+
+            let idle = !self.queue.main_woken.load(Ordering::SeqCst)
+                && self.queue.tasks.lock().unwrap().is_empty()
+                && self.spawned.borrow().is_empty();
+            if idle {
+                let app = &self.app;
+                app.poll_events(None, |event| queue_event(app, event));
+            }
+        }
+    }
+
+    fn poll_tasks(&self) {
+        let spawned = mem::take(&mut *self.spawned.borrow_mut());
+        for task in spawned {
+            let mut tasks = self.tasks.borrow_mut();
+            let id = match tasks.iter().position(Option::is_none) {
+                Some(id) => {
+                    tasks[id] = Some(task);
+                    id
+                }
+                None => {
+                    tasks.push(Some(task));
+                    tasks.len() - 1
+                }
+            };
+            self.queue.tasks.lock().unwrap().push(id);
+        }
+
+        let ready = mem::take(&mut *self.queue.tasks.lock().unwrap());
+        for id in ready {
+            // The task is taken out while it's being polled so that it can spawn
+            // more tasks
+            let task = self.tasks.borrow_mut().get_mut(id).and_then(Option::take);
+            if let Some(mut task) = task {
+                let waker = Waker::from(Arc::new(TaskWaker { task: Some(id), queue: self.queue.clone() }));
+                if task.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+                    self.tasks.borrow_mut()[id] = Some(task);
+                }
+            }
+        }
+    }
+}
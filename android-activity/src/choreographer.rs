@@ -0,0 +1,209 @@
+//! Frame callbacks from the `AChoreographer`, delivered by `poll_events()`
+//!
+//! The choreographer runs its callbacks internally, within `ALooper_pollAll()`,
+//! so a callback just records the frame and signals an `eventfd` that's
+//! registered with the looper, which is then delivered like any other looper
+//! source.
+//!
+//! The choreographer API has grown over several releases, so the best available
+//! callback is looked up at runtime:
+//!
+//! - `AChoreographer_postVsyncCallback` (Android 13) also provides the vsync ID
+//! - `AChoreographer_postFrameCallback64` (Android 10) provides a 64-bit frame time
+//!   on all architectures
+//! - `AChoreographer_postFrameCallback` (Android 7) is deprecated because the frame
+//!   time is truncated to 32 bits on 32-bit architectures
+
+use ndk_sys::AChoreographer;
+use std::io;
+use std::os::raw::{c_long, c_void};
+use std::os::unix::prelude::*;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Frame {
+    pub(crate) frame_time_nanos: i64,
+    pub(crate) vsync_id: Option<i64>,
+}
+
+#[derive(Debug)]
+pub(crate) struct FrameCallbacks {
+    choreographer: NonNull<AChoreographer>,
+    eventfd: OwnedFd,
+
+    // Whether the application wants the next frame
+    requested: AtomicBool,
+
+    // Whether a callback has been posted and hasn't run yet, since callbacks
+    // can't be cancelled
+    posted: AtomicBool,
+
+    // The frame that has been signalled via the eventfd, but not yet delivered
+    pending: Mutex<Option<Frame>>,
+}
+
+impl FrameCallbacks {
+    /// Gets the choreographer for the calling thread, which must have a looper
+    pub(crate) fn new() -> io::Result<Self> {
+        let choreographer = NonNull::new(unsafe { ndk_sys::AChoreographer_getInstance() })
+            .ok_or_else(|| io::Error::other("No AChoreographer for the current thread"))?;
+        let fd = unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            choreographer,
+            eventfd: unsafe { OwnedFd::from_raw_fd(fd) },
+            requested: AtomicBool::new(false),
+            posted: AtomicBool::new(false),
+            pending: Mutex::new(None),
+        })
+    }
+
+    /// Requests a single callback for the next frame
+    pub(crate) fn request(self: &Arc<Self>) {
+        self.requested.store(true, Ordering::SeqCst);
+        if !self.posted.swap(true, Ordering::SeqCst) {
+            // The callback owns a reference to the state until it runs
+            let data = Arc::into_raw(self.clone()) as *mut c_void;
+            unsafe { post_callback(self.choreographer.as_ptr(), data) };
+        }
+    }
+
+    /// Cancels a requested frame, including a frame that's been signalled but not
+    /// yet delivered
+    pub(crate) fn cancel(&self) {
+        self.requested.store(false, Ordering::SeqCst);
+        *self.pending.lock().unwrap() = None;
+    }
+
+    fn on_frame(&self, frame: Frame) {
+        self.posted.store(false, Ordering::SeqCst);
+        if self.requested.swap(false, Ordering::SeqCst) {
+            *self.pending.lock().unwrap() = Some(frame);
+            let count = 1u64;
+            unsafe {
+                libc::write(self.eventfd.as_raw_fd(), &count as *const u64 as *const c_void, std::mem::size_of::<u64>());
+            }
+        }
+    }
+
+    /// Takes the pending frame, once the looper has reported that the `eventfd`
+    /// is readable
+    pub(crate) fn take(&self) -> Option<Frame> {
+        let mut count = 0u64;
+        unsafe {
+            libc::read(self.eventfd.as_raw_fd(), &mut count as *mut u64 as *mut c_void, std::mem::size_of::<u64>());
+        }
+        self.pending.lock().unwrap().take()
+    }
+}
+
+// The choreographer pointer is only used on the application's main thread
+unsafe impl Send for FrameCallbacks {}
+unsafe impl Sync for FrameCallbacks {}
+
+impl AsRawFd for FrameCallbacks {
+    fn as_raw_fd(&self) -> RawFd {
+        self.eventfd.as_raw_fd()
+    }
+}
+
+// Reclaims the reference that was passed to the choreographer as `data`
+unsafe fn frame_callbacks_from_data(data: *mut c_void) -> Arc<FrameCallbacks> {
+    Arc::from_raw(data as *const FrameCallbacks)
+}
+
+unsafe extern "C" fn frame_callback(frame_time_nanos: c_long, data: *mut c_void) {
+    frame_callbacks_from_data(data).on_frame(Frame { frame_time_nanos: frame_time_nanos as _, vsync_id: None });
+}
+
+// Only used on a real device, since the test backend's choreographer can't be
+// found via `dlsym()`
+#[cfg(not(feature = "test"))]
+mod dynamic {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[repr(C)]
+    pub(super) struct AChoreographerFrameCallbackData {
+        _private: [u8; 0],
+    }
+
+    pub(super) type PostFrameCallback64 =
+        unsafe extern "C" fn(*mut AChoreographer, unsafe extern "C" fn(i64, *mut c_void), *mut c_void);
+    pub(super) type PostVsyncCallback = unsafe extern "C" fn(
+        *mut AChoreographer,
+        unsafe extern "C" fn(*const AChoreographerFrameCallbackData, *mut c_void),
+        *mut c_void,
+    );
+
+    pub(super) struct VsyncApi {
+        pub(super) post: PostVsyncCallback,
+        pub(super) frame_time_nanos: unsafe extern "C" fn(*const AChoreographerFrameCallbackData) -> i64,
+        pub(super) preferred_index: unsafe extern "C" fn(*const AChoreographerFrameCallbackData) -> usize,
+        pub(super) vsync_id: unsafe extern "C" fn(*const AChoreographerFrameCallbackData, usize) -> i64,
+    }
+
+    pub(super) struct Api {
+        pub(super) vsync: Option<VsyncApi>,
+        pub(super) post64: Option<PostFrameCallback64>,
+    }
+
+    unsafe fn lookup<T: Copy>(name: &CStr) -> Option<T> {
+        let symbol = libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr());
+        if symbol.is_null() {
+            None
+        } else {
+            Some(std::mem::transmute_copy(&symbol))
+        }
+    }
+
+    pub(super) fn api() -> &'static Api {
+        static API: std::sync::OnceLock<Api> = std::sync::OnceLock::new();
+        API.get_or_init(|| unsafe {
+            let vsync = (|| {
+                Some(VsyncApi {
+                    post: lookup(c"AChoreographer_postVsyncCallback")?,
+                    frame_time_nanos: lookup(c"AChoreographerFrameCallbackData_getFrameTimeNanos")?,
+                    preferred_index: lookup(c"AChoreographerFrameCallbackData_getPreferredFrameTimelineIndex")?,
+                    vsync_id: lookup(c"AChoreographerFrameCallbackData_getFrameTimelineVsyncId")?,
+                })
+            })();
+            Api {
+                vsync,
+                post64: lookup(c"AChoreographer_postFrameCallback64"),
+            }
+        })
+    }
+
+    pub(super) unsafe extern "C" fn vsync_callback(callback_data: *const AChoreographerFrameCallbackData, data: *mut c_void) {
+        let vsync = api().vsync.as_ref().unwrap();
+        let frame_time_nanos = (vsync.frame_time_nanos)(callback_data);
+        let vsync_id = (vsync.vsync_id)(callback_data, (vsync.preferred_index)(callback_data));
+        frame_callbacks_from_data(data).on_frame(Frame { frame_time_nanos, vsync_id: Some(vsync_id) });
+    }
+
+    pub(super) unsafe extern "C" fn frame_callback64(frame_time_nanos: i64, data: *mut c_void) {
+        frame_callbacks_from_data(data).on_frame(Frame { frame_time_nanos, vsync_id: None });
+    }
+}
+
+#[cfg(not(feature = "test"))]
+unsafe fn post_callback(choreographer: *mut AChoreographer, data: *mut c_void) {
+    let api = dynamic::api();
+    if let Some(vsync) = &api.vsync {
+        (vsync.post)(choreographer, dynamic::vsync_callback, data);
+    } else if let Some(post64) = api.post64 {
+        post64(choreographer, dynamic::frame_callback64, data);
+    } else {
+        ndk_sys::AChoreographer_postFrameCallback(choreographer, Some(frame_callback), data);
+    }
+}
+
+#[cfg(feature = "test")]
+unsafe fn post_callback(choreographer: *mut AChoreographer, data: *mut c_void) {
+    ndk_sys::AChoreographer_postFrameCallback(choreographer, Some(frame_callback), data);
+}
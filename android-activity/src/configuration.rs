@@ -0,0 +1,58 @@
+//! Configuration changes, as delivered with [`MainEvent::ConfigChanged`](crate::MainEvent::ConfigChanged)
+
+use bitflags::bitflags;
+use ndk::configuration::Configuration;
+
+bitflags! {
+    /// The set of fields that differ between two [`Configuration`]s
+    ///
+    /// These follow the `ACONFIGURATION_*` flags that are returned by `AConfiguration_diff()`
+    /// and correspond to the `android:configChanges` values that an Activity can handle,
+    /// so for example a [`ConfigurationDiff::LOCALE`] change might only need text to be
+    /// re-laid out while a [`ConfigurationDiff::DENSITY`] change might mean that GPU
+    /// resources need to be recreated at a new scale.
+    pub struct ConfigurationDiff: u32 {
+        /// The mobile country code changed
+        const MCC = ndk_sys::ACONFIGURATION_MCC;
+        /// The mobile network code changed
+        const MNC = ndk_sys::ACONFIGURATION_MNC;
+        /// The language or country changed
+        const LOCALE = ndk_sys::ACONFIGURATION_LOCALE;
+        /// The type of touchscreen changed
+        const TOUCHSCREEN = ndk_sys::ACONFIGURATION_TOUCHSCREEN;
+        /// The type of keyboard changed
+        const KEYBOARD = ndk_sys::ACONFIGURATION_KEYBOARD;
+        /// The availability of the keyboard or navigation keys changed, such as when a
+        /// hardware keyboard is connected
+        const KEYBOARD_HIDDEN = ndk_sys::ACONFIGURATION_KEYBOARD_HIDDEN;
+        /// The type of navigation changed
+        const NAVIGATION = ndk_sys::ACONFIGURATION_NAVIGATION;
+        /// The screen orientation changed
+        const ORIENTATION = ndk_sys::ACONFIGURATION_ORIENTATION;
+        /// The screen density changed
+        const DENSITY = ndk_sys::ACONFIGURATION_DENSITY;
+        /// The available screen size changed
+        const SCREEN_SIZE = ndk_sys::ACONFIGURATION_SCREEN_SIZE;
+        /// The platform version changed
+        const VERSION = ndk_sys::ACONFIGURATION_VERSION;
+        /// The screen size class or aspect ratio changed
+        const SCREEN_LAYOUT = ndk_sys::ACONFIGURATION_SCREEN_LAYOUT;
+        /// The UI mode changed, such as switching night mode or docking the device
+        const UI_MODE = ndk_sys::ACONFIGURATION_UI_MODE;
+        /// The smallest available screen size changed
+        const SMALLEST_SCREEN_SIZE = ndk_sys::ACONFIGURATION_SMALLEST_SCREEN_SIZE;
+        /// The layout direction changed
+        const LAYOUT_DIR = ndk_sys::ACONFIGURATION_LAYOUTDIR;
+        /// Whether the screen is round changed
+        const SCREEN_ROUND = ndk_sys::ACONFIGURATION_SCREEN_ROUND;
+        /// The color mode (wide color gamut or HDR) changed
+        const COLOR_MODE = ndk_sys::ACONFIGURATION_COLOR_MODE;
+    }
+}
+
+impl ConfigurationDiff {
+    /// Compares two configurations via `AConfiguration_diff()`
+    pub(crate) fn between(old: &Configuration, new: &Configuration) -> Self {
+        Self::from_bits_truncate(old.diff(new).0)
+    }
+}
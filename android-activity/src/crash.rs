@@ -0,0 +1,76 @@
+//! Panic handling for `android_main`
+//!
+//! The glue layer calls `android_main` from a C entry point, so a panic must not
+//! be allowed to unwind out of it. Instead the panic is caught and the Activity is
+//! finished, so that the application is closed cleanly.
+//!
+//! The panic hook logs every panic (from any thread) to logcat under the
+//! `RustPanic` tag, along with a backtrace, and also writes a crash report to the
+//! `crash-reports` directory within the application's internal data directory.
+//! Backtraces are only symbolized if the library is built with debug info.
+
+use log::Level;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::ffi::CStr;
+use std::fs;
+use std::panic::{self, Location};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::logger;
+
+const TAG: &CStr = c"RustPanic";
+
+// The number of crash reports that are kept, including the latest
+const MAX_REPORTS: usize = 8;
+
+fn report(payload: &dyn Any, location: Option<&Location<'_>>, backtrace: &Backtrace) -> String {
+    let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message,
+        (None, Some(message)) => message.as_str(),
+        (None, None) => "Box<dyn Any>",
+    };
+    let thread = thread::current();
+    let thread = thread.name().unwrap_or("<unnamed>");
+    match location {
+        Some(location) => format!("thread '{thread}' panicked at {location}:\n{message}\n\nstack backtrace:\n{backtrace}"),
+        None => format!("thread '{thread}' panicked:\n{message}\n\nstack backtrace:\n{backtrace}"),
+    }
+}
+
+// Writes a new crash report to `dir` and removes the oldest reports
+fn write_report(dir: &Path, report: &str) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    let path = dir.join(format!("panic-{millis:013}-{}.txt", std::process::id()));
+    fs::write(&path, report)?;
+
+    let mut reports: Vec<_> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with("panic-")))
+        .collect();
+    reports.sort();
+    if reports.len() > MAX_REPORTS {
+        for old in &reports[..reports.len() - MAX_REPORTS] {
+            let _ = fs::remove_file(old);
+        }
+    }
+    Ok(path)
+}
+
+/// Replaces the panic hook with one that logs to logcat and writes crash reports
+/// to `report_dir`, if known
+pub(crate) fn install_panic_hook(report_dir: Option<PathBuf>) {
+    panic::set_hook(Box::new(move |info| {
+        let report = report(info.payload(), info.location(), &Backtrace::force_capture());
+        logger::write(Level::Error, TAG, &report);
+        if let Some(dir) = &report_dir {
+            match write_report(dir, &report) {
+                Ok(path) => logger::write(Level::Error, TAG, &format!("Crash report written to {}", path.display())),
+                Err(err) => logger::write(Level::Error, TAG, &format!("Failed to write crash report to {}: {err}", dir.display())),
+            }
+        }
+    }));
+}
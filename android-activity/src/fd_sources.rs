@@ -0,0 +1,228 @@
+//! Registration of file descriptors and timers with the application's looper
+//!
+//! Each registered file descriptor is added to the looper with its own `ident`,
+//! which is what identifies the fd when it's returned by `ALooper_pollAll` and is
+//! never reused, so a stale event for an fd that has since been unregistered can
+//! be recognised and dropped. Timers, user events and choreographer frames are
+//! registered in the same way, via a `timerfd` or `eventfd` that's owned by the
+//! looper source.
+
+use log::trace;
+use ndk::looper::FdEvent;
+use ndk_sys::ALooper;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::io;
+use std::os::unix::prelude::*;
+use std::ptr::{self, NonNull};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::choreographer::FrameCallbacks;
+use crate::timer::{TimerFd, TimerId};
+use crate::user_events::UserEventQueue;
+use crate::PollEvent;
+
+// Idents below this are reserved for the glue layer's own sources
+const FIRST_USER_IDENT: i32 = 3;
+
+/// Identifies a file descriptor that was registered via
+/// [`AndroidApp::register_fd()`](crate::AndroidApp::register_fd)
+///
+/// Each registration gets a new token, even if the same file descriptor is
+/// registered again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FdToken(i32);
+
+impl FdToken {
+    pub(crate) fn ident(self) -> i32 {
+        self.0
+    }
+
+    pub(crate) fn from_ident(ident: i32) -> Self {
+        Self(ident)
+    }
+}
+
+#[derive(Debug)]
+enum Source {
+    Fd(RawFd),
+    Timer(TimerFd),
+    UserEvents(Arc<UserEventQueue>),
+    Frames(Arc<FrameCallbacks>),
+}
+
+impl Source {
+    fn fd(&self) -> RawFd {
+        match self {
+            Source::Fd(fd) => *fd,
+            Source::Timer(timer) => timer.as_raw_fd(),
+            Source::UserEvents(queue) => queue.as_raw_fd(),
+            Source::Frames(frames) => frames.as_raw_fd(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FdSources {
+    looper: NonNull<ALooper>,
+    next_ident: Cell<i32>,
+    sources: RefCell<HashMap<i32, Source>>,
+}
+
+impl FdSources {
+    /// # Safety
+    /// `looper` must be a valid looper pointer
+    pub(crate) unsafe fn new(looper: NonNull<ALooper>) -> Self {
+        // Make sure the looper outlives our registrations
+        ndk_sys::ALooper_acquire(looper.as_ptr());
+        Self {
+            looper,
+            next_ident: Cell::new(FIRST_USER_IDENT),
+            sources: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn add(&self, source: Source, interest: FdEvent) -> io::Result<i32> {
+        let fd = source.fd();
+        let ident = self.next_ident.get();
+        let res = unsafe {
+            ndk_sys::ALooper_addFd(self.looper.as_ptr(), fd, ident, interest.bits() as _, None, ptr::null_mut())
+        };
+        if res != 1 {
+            return Err(io::Error::other(format!("Failed to add fd {fd} to the looper")));
+        }
+        self.next_ident.set(ident + 1);
+
+        // The looper replaces any existing registration for the same fd
+        let mut sources = self.sources.borrow_mut();
+        sources.retain(|_, registered| registered.fd() != fd);
+        sources.insert(ident, source);
+        trace!("Added fd {fd} to the looper with ident {ident}, interest = {interest:?}");
+        Ok(ident)
+    }
+
+    // The source is only dropped (closing a timerfd) after it's removed from the looper
+    fn remove(&self, ident: i32) -> Option<Source> {
+        let source = self.sources.borrow_mut().remove(&ident)?;
+        unsafe { ndk_sys::ALooper_removeFd(self.looper.as_ptr(), source.fd()) };
+        trace!("Removed fd {} with ident {ident} from the looper", source.fd());
+        Some(source)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn looper(&self) -> NonNull<ALooper> {
+        self.looper
+    }
+
+    pub(crate) fn register(&self, fd: RawFd, interest: FdEvent) -> io::Result<FdToken> {
+        self.add(Source::Fd(fd), interest).map(FdToken)
+    }
+
+    pub(crate) fn unregister(&self, token: FdToken) -> bool {
+        let is_fd = matches!(self.sources.borrow().get(&token.0), Some(Source::Fd(_)));
+        is_fd && self.remove(token.0).is_some()
+    }
+
+    pub(crate) fn add_timer(&self, delay: Duration, repeat: bool) -> io::Result<TimerId> {
+        let timer = TimerFd::new()?;
+        timer.set(delay, repeat)?;
+        self.add(Source::Timer(timer), FdEvent::INPUT).map(TimerId::from_ident)
+    }
+
+    pub(crate) fn reschedule_timer(&self, id: TimerId, delay: Duration, repeat: bool) -> io::Result<()> {
+        match self.sources.borrow().get(&id.ident()) {
+            Some(Source::Timer(timer)) => timer.set(delay, repeat),
+            _ => Err(io::Error::new(io::ErrorKind::NotFound, format!("Unknown timer {id:?}"))),
+        }
+    }
+
+    pub(crate) fn cancel_timer(&self, id: TimerId) -> bool {
+        let is_timer = matches!(self.sources.borrow().get(&id.ident()), Some(Source::Timer(_)));
+        is_timer && self.remove(id.ident()).is_some()
+    }
+
+    /// Returns the queue for user events, which is registered with the looper
+    /// the first time it's needed
+    pub(crate) fn user_events(&self) -> io::Result<Arc<UserEventQueue>> {
+        let existing = self.sources.borrow().values().find_map(|source| match source {
+            Source::UserEvents(queue) => Some(queue.clone()),
+            _ => None,
+        });
+        match existing {
+            Some(queue) => Ok(queue),
+            None => {
+                let queue = Arc::new(UserEventQueue::new()?);
+                self.add(Source::UserEvents(queue.clone()), FdEvent::INPUT)?;
+                Ok(queue)
+            }
+        }
+    }
+
+    /// Returns the choreographer frame callbacks, which are registered with the
+    /// looper the first time they're needed
+    pub(crate) fn frame_callbacks(&self) -> io::Result<Arc<FrameCallbacks>> {
+        let existing = self.sources.borrow().values().find_map(|source| match source {
+            Source::Frames(frames) => Some(frames.clone()),
+            _ => None,
+        });
+        match existing {
+            Some(frames) => Ok(frames),
+            None => {
+                let frames = Arc::new(FrameCallbacks::new()?);
+                self.add(Source::Frames(frames.clone()), FdEvent::INPUT)?;
+                Ok(frames)
+            }
+        }
+    }
+
+    /// Cancels any requested frame, without registering the frame callbacks if
+    /// they haven't been used yet
+    pub(crate) fn cancel_frame(&self) {
+        for source in self.sources.borrow().values() {
+            if let Source::Frames(frames) = source {
+                frames.cancel();
+            }
+        }
+    }
+
+    /// Maps an ident returned by `ALooper_pollAll` to the event for a registered
+    /// fd or timer
+    ///
+    /// Returns `None` if the source was removed after the looper had already
+    /// queued an event for it, for sources that were added to the looper
+    /// directly, or if a timer hasn't actually expired (e.g. if it was
+    /// rescheduled since the looper queued the event).
+    pub(crate) fn event(&self, ident: i32, events: FdEvent) -> Option<PollEvent<'static>> {
+        match self.sources.borrow().get(&ident)? {
+            Source::Fd(_) => Some(PollEvent::Fd { token: FdToken(ident), events }),
+            Source::Timer(timer) => {
+                if timer.read() > 0 {
+                    Some(PollEvent::Timer(TimerId::from_ident(ident)))
+                } else {
+                    None
+                }
+            }
+            Source::UserEvents(queue) => queue.pop().map(PollEvent::User),
+            Source::Frames(frames) => frames.take().map(|frame| PollEvent::Frame {
+                frame_time_nanos: frame.frame_time_nanos,
+                vsync_id: frame.vsync_id,
+            }),
+        }
+    }
+}
+
+impl Drop for FdSources {
+    fn drop(&mut self) {
+        for source in self.sources.get_mut().values() {
+            unsafe { ndk_sys::ALooper_removeFd(self.looper.as_ptr(), source.fd()) };
+            match source {
+                Source::UserEvents(queue) => queue.close(),
+                Source::Frames(frames) => frames.cancel(),
+                _ => {}
+            }
+        }
+        self.sources.get_mut().clear();
+        unsafe { ndk_sys::ALooper_release(self.looper.as_ptr()) };
+    }
+}
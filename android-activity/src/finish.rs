@@ -0,0 +1,81 @@
+//! Finishing the Activity, from any thread
+//!
+//! `ANativeActivity_finish()` and `GameActivity_finish()` only post a request
+//! to the Java main thread, so they're safe to call from any thread, but only
+//! while the Activity exists. The Activity can be freed as soon as the
+//! application thread has exited, so the [`Finisher`] is cleared beforehand, by
+//! `AndroidAppGuard`'s `Drop` implementation in `lib.rs`, after which finishing
+//! is a no-op.
+
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+// The backend's Activity (or `android_app`) pointer, and the function that
+// finishes it
+type FinishTarget = (NonNull<c_void>, unsafe fn(NonNull<c_void>));
+
+#[derive(Debug)]
+pub(crate) struct Finisher {
+    // Held while finishing, so the target can't be cleared (and then freed)
+    // by the application thread at the same time
+    target: Mutex<Option<FinishTarget>>,
+    requested: AtomicBool,
+}
+
+// The target is only used while the mutex is held, and the finish functions are
+// thread safe
+unsafe impl Send for Finisher {}
+unsafe impl Sync for Finisher {}
+
+impl Finisher {
+    /// # Safety
+    /// `finish(activity)` must be safe to call from any thread until
+    /// [`Finisher::clear()`] is called
+    pub(crate) unsafe fn new(activity: NonNull<c_void>, finish: unsafe fn(NonNull<c_void>)) -> Arc<Self> {
+        Arc::new(Self { target: Mutex::new(Some((activity, finish))), requested: AtomicBool::new(false) })
+    }
+
+    pub(crate) fn finish(&self) {
+        let target = self.target.lock().unwrap();
+        if let Some((activity, finish)) = *target {
+            if !self.requested.swap(true, Ordering::SeqCst) {
+                unsafe { finish(activity) };
+            }
+        }
+    }
+
+    /// Whether the Activity has been asked to finish
+    #[cfg(feature = "test")]
+    pub(crate) fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    /// Makes any further calls to [`Finisher::finish()`] no-ops, waiting for
+    /// any call that's in progress on another thread
+    pub(crate) fn clear(&self) {
+        self.target.lock().unwrap().take();
+    }
+}
+
+/// Finishes the application's Activity from any thread, as returned by
+/// [`AndroidApp::create_finisher()`](crate::AndroidApp::create_finisher)
+#[derive(Debug, Clone)]
+pub struct ActivityFinisher {
+    finisher: Arc<Finisher>,
+}
+
+impl ActivityFinisher {
+    pub(crate) fn new(finisher: Arc<Finisher>) -> Self {
+        Self { finisher }
+    }
+
+    /// Asks the Java main thread to finish the Activity, like
+    /// [`AndroidApp::finish()`](crate::AndroidApp::finish)
+    ///
+    /// This does nothing once `android_main` has returned.
+    pub fn finish(&self) {
+        self.finisher.finish();
+    }
+}
@@ -8095,12 +8095,14 @@ pub struct android_app {
     pub pendingContentRect: ARect,
     pub keyEventFilter: android_key_event_filter,
     pub motionEventFilter: android_motion_event_filter,
+    #[doc = " The Rust glue layer's input buffer, see `_rust_glue_create_input_buffer()`"]
+    pub rustInputBuffer: *mut ::std::os::raw::c_void,
 }
 #[test]
 fn bindgen_test_layout_android_app() {
     assert_eq!(
         ::std::mem::size_of::<android_app>(),
-        55288usize,
+        55296usize,
         concat!("Size of: ", stringify!(android_app))
     );
     assert_eq!(
@@ -8388,6 +8390,16 @@ fn bindgen_test_layout_android_app() {
             stringify!(motionEventFilter)
         )
     );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<android_app>())).rustInputBuffer as *const _ as usize },
+        55288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(android_app),
+            "::",
+            stringify!(rustInputBuffer)
+        )
+    );
 }
 #[doc = " Looper data ID of commands coming from the app's main thread, which"]
 #[doc = " is returned as an identifier from ALooper_pollOnce().  The data for this"]
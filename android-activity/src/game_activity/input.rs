@@ -15,12 +15,17 @@
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use std::{convert::TryInto, ops::Deref};
-use crate::ffi::{GameActivityMotionEvent, GameActivityKeyEvent};
+use super::ffi::{GameActivityMotionEvent, GameActivityKeyEvent};
 
 use bitflags::bitflags;
 
 // Note: try to keep this wrapper API compatible with the AInputEvent API if possible
 
+// A `MotionEvent` is a copy of the fixed size `GameActivityMotionEvent` struct,
+// with room for every pointer, but it isn't boxed since events are only passed
+// to applications by reference, and boxing would mean an allocation for every
+// buffered event
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum InputEvent {
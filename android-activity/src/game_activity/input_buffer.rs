@@ -0,0 +1,85 @@
+//! A growable buffer for input events that arrive on the Java main thread
+//!
+//! `android_native_app_glue` buffers at most 16 motion events and 4 key events
+//! between calls to `android_app_swap_input_buffers()` and silently drops
+//! anything else, which can easily lose key-up events if a frame stalls. Instead
+//! the glue layer hands every event over to this buffer which grows as needed, up
+//! to a configurable limit that only exists to bound memory usage when an
+//! application doesn't process input at all.
+//!
+//! Key and motion events are buffered in a single queue, so they're delivered
+//! in the order they arrived. Historical motion samples aren't buffered, since
+//! the input API doesn't expose them, so each motion event only carries its most
+//! recent sample.
+
+use std::sync::Mutex;
+
+use super::ffi::{GameActivityKeyEvent, GameActivityMotionEvent};
+use super::input::{InputEvent, KeyEvent, MotionEvent};
+
+/// The default limit for the number of events that may be buffered between
+/// calls to `input_events()`
+pub(crate) const DEFAULT_MAX_BUFFERED_EVENTS: usize = 1024;
+
+#[derive(Debug, Default)]
+pub(crate) struct BufferedEvents {
+    pub(crate) events: Vec<InputEvent>,
+
+    /// The number of events that were dropped because the buffer was full
+    pub(crate) overflowed: usize,
+}
+
+#[derive(Debug)]
+struct State {
+    events: BufferedEvents,
+    max_events: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct InputBuffer {
+    state: Mutex<State>,
+}
+
+impl Default for InputBuffer {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(State {
+                events: BufferedEvents::default(),
+                max_events: DEFAULT_MAX_BUFFERED_EVENTS,
+            }),
+        }
+    }
+}
+
+impl InputBuffer {
+    pub(crate) fn set_max_events(&self, max_events: usize) {
+        self.state.lock().unwrap().max_events = max_events;
+    }
+
+    pub(crate) fn push_key_event(&self, event: GameActivityKeyEvent) {
+        self.push(InputEvent::KeyEvent(KeyEvent::new(event)));
+    }
+
+    pub(crate) fn push_motion_event(&self, event: GameActivityMotionEvent) {
+        self.push(InputEvent::MotionEvent(MotionEvent::new(event)));
+    }
+
+    fn push(&self, event: InputEvent) {
+        let mut state = self.state.lock().unwrap();
+        if state.events.events.len() >= state.max_events {
+            state.events.overflowed += 1;
+        } else {
+            state.events.events.push(event);
+        }
+    }
+
+    /// Takes all the buffered events, leaving the buffer empty
+    ///
+    /// This is the equivalent of swapping the glue layer's double buffers, so
+    /// new events can continue to be buffered while the application processes
+    /// the events that were taken.
+    pub(crate) fn take(&self) -> BufferedEvents {
+        let _section = crate::trace::glue_section(c"input_events buffer swap");
+        std::mem::take(&mut self.state.lock().unwrap().events)
+    }
+}
@@ -0,0 +1,471 @@
+use jni_sys::*;
+use log::error;
+use ndk::asset::AssetManager;
+use ndk::configuration::Configuration;
+use ndk::native_window::NativeWindow;
+use ndk_sys::ANativeWindow;
+use std::cell::RefCell;
+use std::os::unix::prelude::*;
+use std::path::PathBuf;
+use std::ptr::NonNull;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::ptr;
+
+use crate::{AndroidApp, AndroidAppWaker, Assets, InsetType, Rect, Size, TrimMemoryLevel, WindowInsets};
+use crate::fd_sources::FdSources;
+use crate::finish::Finisher;
+use crate::poll::{AppCmd, GlueBackend, PollState};
+use crate::state_spill::StateSpill;
+use crate::lifecycle::Lifecycle;
+use crate::recording::RecordingState;
+
+use self::input::{Axis, InputEvent};
+use self::input_buffer::InputBuffer;
+
+pub(crate) mod ffi;
+
+pub mod input;
+mod input_buffer;
+
+/// `GameActivity` specific extensions to [`AndroidApp`]
+pub trait AndroidAppExt {
+    /// Enables the capture of the given `axis` for pointer input events
+    ///
+    /// By default only the X and Y axis are captured for pointer events and any other
+    /// axis must be explicitly enabled / disabled
+    fn enable_motion_axis(&self, axis: Axis);
+
+    /// Disables the capture of the given `axis` for pointer input events
+    ///
+    /// By default only the X and Y axis are captured for pointer events and any other
+    /// axis must be explicitly enabled / disabled
+    fn disable_motion_axis(&self, axis: Axis);
+
+    /// Sets the maximum number of input events that may be buffered between
+    /// calls to [`AndroidApp::input_events()`]
+    ///
+    /// The input buffer grows as needed up to this limit (1024 events by
+    /// default), after which new events are dropped and counted in the value
+    /// returned by the next call to [`AndroidApp::input_events()`].
+    fn set_max_buffered_input_events(&self, max_events: usize);
+}
+
+impl AndroidAppExt for AndroidApp {
+    fn enable_motion_axis(&self, axis: Axis) {
+        unsafe {
+            ffi::GameActivityPointerAxes_enableAxis(axis as i32)
+        }
+    }
+
+    fn disable_motion_axis(&self, axis: Axis) {
+        unsafe {
+            ffi::GameActivityPointerAxes_disableAxis(axis as i32)
+        }
+    }
+
+    fn set_max_buffered_input_events(&self, max_events: usize) {
+        self.inner.input_buffer.set_max_events(max_events);
+    }
+}
+
+// The only time it's safe to update the android_app->savedState pointer is
+// while handling a SaveState event, so this API is only exposed for those
+// events...
+#[derive(Debug)]
+pub(crate) struct StateSaver<'a> {
+    pub(crate) app: &'a AndroidAppInner,
+}
+
+impl<'a> StateSaver<'a> {
+    pub fn store(&self, state: &[u8]) {
+
+        // android_native_app_glue specifically expects savedState to have been allocated
+        // via libc::malloc since it will automatically handle freeing the data once it
+        // has been handed over to the Java Activity / main thread.
+        unsafe {
+            let app_ptr = self.app.ptr.as_ptr();
+
+            // In case the application calls store() multiple times for some reason we
+            // make sure to free any pre-existing state...
+            if (*app_ptr).savedState != ptr::null_mut() {
+                libc::free((*app_ptr).savedState);
+                (*app_ptr).savedState = ptr::null_mut();
+                (*app_ptr).savedStateSize = 0;
+            }
+
+            let buf = libc::malloc(state.len());
+            if buf == ptr::null_mut() {
+                panic!("Failed to allocate save_state buffer");
+            }
+
+            // Since it's a byte array there's no special alignment requirement here.
+            //
+            // Since we re-define `buf` we ensure it's not possible to access the buffer
+            // via its original pointer for the lifetime of the slice.
+            {
+                let buf: &mut [u8] = std::slice::from_raw_parts_mut(buf.cast(), state.len());
+                buf.copy_from_slice(state);
+            }
+
+            (*app_ptr).savedState = buf;
+            (*app_ptr).savedStateSize = state.len() as u64;
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct StateLoader<'a> {
+    pub(crate) app: &'a AndroidAppInner,
+}
+impl<'a> StateLoader<'a> {
+    pub fn load(&self) -> Option<Vec<u8>> {
+        unsafe {
+            let app_ptr = self.app.ptr.as_ptr();
+            if (*app_ptr).savedState != ptr::null_mut() && (*app_ptr).savedStateSize > 0 {
+                let buf: &mut [u8] = std::slice::from_raw_parts_mut((*app_ptr).savedState.cast(), (*app_ptr).savedStateSize as usize);
+                let state = buf.to_vec();
+                Some(state)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct AndroidAppInner {
+    ptr: NonNull<ffi::android_app>,
+    input_buffer: Arc<InputBuffer>,
+    poll: PollState,
+    lifecycle: Lifecycle,
+    recording: RefCell<RecordingState>,
+    state_spill: StateSpill,
+    finisher: Arc<Finisher>,
+}
+
+impl AndroidAppInner {
+
+    pub(crate) unsafe fn from_ptr(ptr: NonNull<ffi::android_app>) -> AndroidApp {
+
+        // Note: we don't use from_ptr since we don't own the android_app.config
+        // and need to keep in mind that the Drop handler is going to call
+        // AConfiguration_delete()
+        //
+        // Whenever we get a ConfigChanged notification we synchronize this
+        // config state with a deep copy.
+        let config = Configuration::clone_from_ptr(NonNull::new_unchecked((*ptr.as_ptr()).config));
+
+        // The glue layer holds its own reference to the input buffer until
+        // the activity is destroyed
+        let input_buffer = (*ptr.as_ptr()).rustInputBuffer as *const InputBuffer;
+        Arc::increment_strong_count(input_buffer);
+        let input_buffer = Arc::from_raw(input_buffer);
+
+        let app_ptr = ptr.as_ptr();
+        let saved_state = if !(*app_ptr).savedState.is_null() && (*app_ptr).savedStateSize > 0 {
+            Some(std::slice::from_raw_parts((*app_ptr).savedState as *const u8, (*app_ptr).savedStateSize as usize))
+        } else {
+            None
+        };
+        let state_spill = StateSpill::new(crate::path_from_ptr((*(*app_ptr).activity).internalDataPath), saved_state);
+
+        AndroidApp {
+            inner: Rc::new(AndroidAppInner {
+                ptr,
+                input_buffer,
+                poll: PollState::new(config, FdSources::new(NonNull::new_unchecked((*ptr.as_ptr()).looper))),
+                lifecycle: Lifecycle::default(),
+                recording: RefCell::new(RecordingState::default()),
+                state_spill,
+                finisher: Finisher::new(NonNull::new_unchecked((*ptr.as_ptr()).activity).cast(), finish_activity),
+            })
+        }
+    }
+
+    // Reads the payload that the glue layer writes to the command pipe right
+    // after some commands (see `android_app_write_cmd_payload()`)
+    unsafe fn read_cmd_payload<T: Copy>(&self) -> T {
+        let mut payload = std::mem::MaybeUninit::<T>::zeroed();
+        let size = std::mem::size_of::<T>();
+        if libc::read((*self.ptr.as_ptr()).msgread, payload.as_mut_ptr().cast(), size) != size as isize {
+            error!("Failed to read command payload: {}", std::io::Error::last_os_error());
+        }
+        payload.assume_init()
+    }
+
+    pub fn create_waker(&self) -> AndroidAppWaker {
+        unsafe {
+            // From the application's pov we assume the app_ptr and looper pointer
+            // have static lifetimes and we can safely assume they are never NULL.
+            let app_ptr = self.ptr.as_ptr();
+            AndroidAppWaker { looper: NonNull::new_unchecked((*app_ptr).looper) }
+        }
+    }
+
+    pub(crate) fn lifecycle(&self) -> &Lifecycle {
+        &self.lifecycle
+    }
+
+    pub(crate) fn recording(&self) -> &RefCell<RecordingState> {
+        &self.recording
+    }
+
+    pub(crate) fn state_spill(&self) -> &StateSpill {
+        &self.state_spill
+    }
+
+    pub fn content_rect(&self) -> Rect {
+        unsafe {
+            let app_ptr = self.ptr.as_ptr();
+            Rect {
+                left: (*app_ptr).contentRect.left,
+                right: (*app_ptr).contentRect.right,
+                top: (*app_ptr).contentRect.top,
+                bottom: (*app_ptr).contentRect.bottom,
+            }
+        }
+    }
+
+    pub fn asset_manager(&self) -> AssetManager {
+        unsafe {
+            let app_ptr = self.ptr.as_ptr();
+            let am_ptr = NonNull::new_unchecked((*(*app_ptr).activity).assetManager);
+            AssetManager::from_ptr(am_ptr)
+        }
+    }
+
+    pub(crate) fn finisher(&self) -> &Arc<Finisher> {
+        &self.finisher
+    }
+
+    pub fn assets(&self) -> Assets {
+        Assets::from_asset_manager(self.asset_manager())
+    }
+
+    // To optimize the capture of pointer data then by default only the X
+    // and Y pointer [Axis] are recorded. Additional axis can be enabled and
+    // disabled via [`AndroidAppExt::enable_motion_axis()`] and
+    // [`AndroidAppExt::disable_motion_axis()`]
+    pub fn internal_data_path(&self) -> Option<PathBuf> {
+        unsafe { crate::path_from_ptr((*(*self.ptr.as_ptr()).activity).internalDataPath) }
+    }
+
+    pub fn external_data_path(&self) -> Option<PathBuf> {
+        unsafe { crate::path_from_ptr((*(*self.ptr.as_ptr()).activity).externalDataPath) }
+    }
+
+    pub fn obb_path(&self) -> Option<PathBuf> {
+        unsafe { crate::path_from_ptr((*(*self.ptr.as_ptr()).activity).obbPath) }
+    }
+
+    pub fn sdk_version(&self) -> u32 {
+        unsafe { (*(*self.ptr.as_ptr()).activity).sdkVersion as u32 }
+    }
+
+    pub fn input_events<F>(&self, mut callback: F) -> usize
+        where F: FnMut(&InputEvent)
+    {
+        let events = self.input_buffer.take();
+
+        for event in &events.events {
+            callback(event);
+        }
+
+        events.overflowed
+    }
+}
+
+// The C glue layer numbers its looper ids and commands like the other backends
+const _: () = assert!(ffi::NativeAppGlueLooperId_LOOPER_ID_MAIN as i32 == crate::poll::LOOPER_ID_MAIN);
+const _: () = assert!(ffi::NativeAppGlueAppCmd_APP_CMD_WINDOW_INSETS_CHANGED as i8 == AppCmd::WindowInsetsChanged as i8);
+
+impl GlueBackend for AndroidAppInner {
+    fn poll_state(&self) -> &PollState {
+        &self.poll
+    }
+
+    fn cmd_fd(&self) -> RawFd {
+        unsafe { (*self.ptr.as_ptr()).msgread }
+    }
+
+    fn read_cmd(&self) -> Option<i8> {
+        let cmd_i = unsafe { ffi::android_app_read_cmd(self.ptr.as_ptr()) };
+        (cmd_i >= 0).then_some(cmd_i)
+    }
+
+    fn pre_exec_cmd(&self, cmd_i: i8) {
+        unsafe {
+            // The Java main thread can't resize a new window until it's been
+            // taken by `android_app_pre_exec_cmd()`, so this is the size it
+            // was created with
+            if cmd_i == AppCmd::InitWindow as i8 {
+                if let Some(window) = NonNull::new((*self.ptr.as_ptr()).pendingWindow) {
+                    let window = NativeWindow::from_ptr(window);
+                    self.poll.set_window_size(Size { width: window.width(), height: window.height() });
+                }
+            }
+
+            ffi::android_app_pre_exec_cmd(self.ptr.as_ptr(), cmd_i);
+        }
+    }
+
+    fn post_exec_cmd(&self, cmd_i: i8) {
+        unsafe { ffi::android_app_post_exec_cmd(self.ptr.as_ptr(), cmd_i); }
+    }
+
+    fn new_window(&self) -> Option<(NonNull<ANativeWindow>, Size)> {
+        let window = unsafe { (*self.ptr.as_ptr()).window };
+        NonNull::new(window).map(|window| (window.cast(), self.poll.window_size()))
+    }
+
+    fn resized_window_size(&self) -> Size {
+        let [width, height] = unsafe { self.read_cmd_payload::<[i32; 2]>() };
+        Size { width, height }
+    }
+
+    fn changed_content_rect(&self) -> Rect {
+        self.content_rect()
+    }
+
+    fn changed_config(&self) -> Configuration {
+        unsafe { Configuration::clone_from_ptr(NonNull::new_unchecked((*self.ptr.as_ptr()).config)) }
+    }
+
+    fn low_memory_level(&self) -> TrimMemoryLevel {
+        TrimMemoryLevel::from_raw(unsafe { self.read_cmd_payload::<i32>() })
+    }
+
+    fn changed_insets(&self) -> WindowInsets {
+        let rects = unsafe { self.read_cmd_payload::<[ffi::ARect; InsetType::COUNT]>() };
+        let mut insets = WindowInsets::default();
+        for (inset_type, rect) in InsetType::ALL.into_iter().zip(rects) {
+            insets.set(inset_type, Rect { left: rect.left, top: rect.top, right: rect.right, bottom: rect.bottom });
+        }
+        insets
+    }
+
+    fn state_loader(&self) -> crate::StateLoader<'_> {
+        crate::StateLoader::new(StateLoader { app: self })
+    }
+
+    fn state_saver(&self) -> crate::StateSaver<'_> {
+        crate::StateSaver::new(StateSaver { app: self })
+    }
+}
+
+// `GameActivity_finish()` only posts a request to the Java main thread, so it
+// can be called from any thread
+unsafe fn finish_activity(activity: NonNull<std::os::raw::c_void>) {
+    ffi::GameActivity_finish(activity.cast().as_ptr());
+}
+
+// Rust doesn't give us a clean way to directly export symbols from C/C++
+// so we rename the C/C++ symbols and re-export these JNI entrypoints from
+// Rust...
+//
+// https://github.com/rust-lang/rfcs/issues/2771
+extern "C" {
+    pub fn Java_com_google_androidgamesdk_GameActivity_loadNativeCode_C(
+        env: *mut JNIEnv,
+        javaGameActivity: jobject,
+        path: jstring,
+        funcName: jstring,
+        internalDataDir: jstring,
+        obbDir: jstring,
+        externalDataDir: jstring,
+        jAssetMgr: jobject,
+        savedState: jbyteArray,
+    ) -> jlong;
+
+    pub fn GameActivity_onCreate_C(
+        activity: *mut ffi::GameActivity,
+        savedState: *mut ::std::os::raw::c_void,
+        savedStateSize: ffi::size_t,
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn Java_com_google_androidgamesdk_GameActivity_loadNativeCode(
+        env: *mut JNIEnv,
+        java_game_activity: jobject,
+        path: jstring,
+        func_name: jstring,
+        internal_data_dir: jstring,
+        obb_dir: jstring,
+        external_data_dir: jstring,
+        jasset_mgr: jobject,
+        saved_state: jbyteArray,
+    ) -> jni_sys::jlong
+{
+    Java_com_google_androidgamesdk_GameActivity_loadNativeCode_C(env, java_game_activity, path, func_name,
+        internal_data_dir, obb_dir, external_data_dir, jasset_mgr, saved_state)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn GameActivity_onCreate(
+        activity: *mut ffi::GameActivity,
+        saved_state: *mut ::std::os::raw::c_void,
+        saved_state_size: ffi::size_t,
+    )
+{
+    GameActivity_onCreate_C(activity, saved_state, saved_state_size);
+}
+
+// This is a spring board between android_native_app_glue and the user's
+// `app_main` function. This is run on a dedicated thread spawned
+// by android_native_app_glue.
+#[no_mangle]
+pub unsafe extern "C" fn _rust_glue_entry(app: *mut ffi::android_app) {
+    let jvm: *mut JavaVM = (*(*app).activity).vm;
+    let activity: jobject = (*(*app).activity).javaGameActivity;
+
+    let app = AndroidAppInner::from_ptr(NonNull::new(app).unwrap());
+
+    crate::run_android_main(app, jvm.cast(), activity.cast());
+}
+
+// The glue layer hands all input events over to an `InputBuffer` which is
+// owned by Rust, via these hooks (see `input_buffer.rs`)
+#[no_mangle]
+pub extern "C" fn _rust_glue_create_input_buffer() -> *mut std::os::raw::c_void {
+    Arc::into_raw(Arc::new(InputBuffer::default())) as *mut _
+}
+
+/// # Safety
+/// `buffer` must have been returned by `_rust_glue_create_input_buffer()`
+#[no_mangle]
+pub unsafe extern "C" fn _rust_glue_free_input_buffer(buffer: *mut std::os::raw::c_void) {
+    drop(Arc::from_raw(buffer as *const InputBuffer));
+}
+
+/// # Safety
+/// `buffer` must have been returned by `_rust_glue_create_input_buffer()`
+#[no_mangle]
+pub unsafe extern "C" fn _rust_glue_push_motion_event(buffer: *mut std::os::raw::c_void, event: *const ffi::GameActivityMotionEvent) {
+    (*(buffer as *const InputBuffer)).push_motion_event(*event);
+}
+
+/// # Safety
+/// `buffer` must have been returned by `_rust_glue_create_input_buffer()`
+#[no_mangle]
+pub unsafe extern "C" fn _rust_glue_push_key_event(buffer: *mut std::os::raw::c_void, event: *const ffi::GameActivityKeyEvent) {
+    (*(buffer as *const InputBuffer)).push_key_event(*event);
+}
+
+// The Activity callbacks in the glue layer are traced via these hooks, with
+// the `atrace` feature (see the `trace` module)
+
+/// # Safety
+/// `name` must be a valid nul terminated string
+#[cfg(feature = "atrace")]
+#[no_mangle]
+pub unsafe extern "C" fn _rust_glue_trace_begin(name: *const std::os::raw::c_char) -> bool {
+    crate::trace::begin_cstr_section(std::ffi::CStr::from_ptr(name))
+}
+
+#[cfg(feature = "atrace")]
+#[no_mangle]
+pub extern "C" fn _rust_glue_trace_end() {
+    crate::trace::end_cstr_section();
+}
@@ -0,0 +1,66 @@
+//! Window insets, as delivered with [`MainEvent::InsetsChanged`](crate::MainEvent::InsetsChanged)
+
+use crate::Rect;
+
+/// The type of component that a window inset is for
+///
+/// These follow Android's
+/// [`WindowInsetsCompat.Type`](https://developer.android.com/reference/androidx/core/view/WindowInsetsCompat.Type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InsetType {
+    CaptionBar,
+    DisplayCutout,
+    Ime,
+    MandatorySystemGestures,
+    NavigationBars,
+    StatusBars,
+    SystemBars,
+    SystemGestures,
+    TappableElement,
+    Waterfall,
+}
+
+impl InsetType {
+    /// The number of inset types, which matches `GAMECOMMON_INSETS_TYPE_COUNT`
+    pub(crate) const COUNT: usize = 10;
+
+    /// All inset types, in the order of `GameCommonInsetsType`
+    pub(crate) const ALL: [InsetType; Self::COUNT] = [
+        InsetType::CaptionBar,
+        InsetType::DisplayCutout,
+        InsetType::Ime,
+        InsetType::MandatorySystemGestures,
+        InsetType::NavigationBars,
+        InsetType::StatusBars,
+        InsetType::SystemBars,
+        InsetType::SystemGestures,
+        InsetType::TappableElement,
+        InsetType::Waterfall,
+    ];
+}
+
+/// A snapshot of the insets of the application's window for each [`InsetType`]
+///
+/// Each inset is represented as a [`Rect`] whose components are the distance
+/// (in pixels) that the inset covers from the corresponding edge of the window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowInsets {
+    insets: [Rect; InsetType::COUNT],
+}
+
+impl WindowInsets {
+    /// Returns the insets for the given type of component
+    pub fn get(&self, inset_type: InsetType) -> &Rect {
+        &self.insets[inset_type as usize]
+    }
+
+    pub(crate) fn set(&mut self, inset_type: InsetType, insets: Rect) {
+        self.insets[inset_type as usize] = insets;
+    }
+
+    /// Iterates over the insets for each type of component
+    pub fn iter(&self) -> impl Iterator<Item = (InsetType, &Rect)> {
+        InsetType::ALL.into_iter().zip(self.insets.iter())
+    }
+}
@@ -0,0 +1,1068 @@
+//! A "glue" layer for building native Rust applications on Android
+//!
+//! This crate exposes a single [`AndroidApp`] API that can be backed by either the
+//! [`NativeActivity`](https://developer.android.com/reference/android/app/NativeActivity)
+//! or [`GameActivity`](https://developer.android.com/games/agdk/integrate-game-activity)
+//! Activity classes. The backend is selected at compile time by enabling exactly one of
+//! the `"native-activity"` or `"game-activity"` cargo features.
+//!
+//! For testing, the `"test"` feature instead selects a host-only backend (see
+//! [`test_activity`]) that emulates the Activity lifecycle so that applications
+//! can be exercised with a plain `cargo test`.
+//!
+//! The optional `"async"` feature adds an [`AndroidApp::events()`] stream that can
+//! be used to handle events from `async` code (see the `async_events` module).
+//!
+//! Anything that is only applicable to one backend is exposed via an extension trait
+//! in the corresponding backend module, such as [`game_activity::AndroidAppExt`].
+
+use ndk::asset::AssetManager;
+use ndk::configuration::Configuration;
+use ndk::looper::FdEvent;
+use ndk_sys::ALooper_wake;
+use ndk_sys::ALooper;
+use std::cell::{OnceCell, RefCell};
+use std::os::unix::prelude::*;
+use std::path::PathBuf;
+use std::ptr::NonNull;
+use std::rc::Rc;
+use std::time::Duration;
+
+// Only needed for running `android_main` on a real device
+#[cfg(not(feature = "test"))]
+use {
+    std::ffi::CStr,
+    std::os::raw,
+    std::os::unix::ffi::OsStrExt,
+};
+
+#[cfg(not(any(target_os = "android", feature = "test")))]
+compile_error!("android-activity only supports compiling for Android");
+
+#[cfg(all(target_os = "android", feature = "test"))]
+compile_error!("The \"test\" feature is only supported when compiling for the host");
+
+#[cfg(any(
+    all(feature = "game-activity", feature = "native-activity"),
+    all(feature = "game-activity", feature = "test"),
+    all(feature = "native-activity", feature = "test"),
+))]
+compile_error!("Only one of the \"game-activity\", \"native-activity\" or \"test\" features can be enabled at the same time");
+
+#[cfg(not(any(feature = "game-activity", feature = "native-activity", feature = "test")))]
+compile_error!("One of the \"game-activity\", \"native-activity\" or \"test\" features must be enabled");
+
+#[cfg(feature = "game-activity")]
+pub mod game_activity;
+#[cfg(feature = "game-activity")]
+use game_activity as activity_impl;
+
+#[cfg(feature = "native-activity")]
+pub mod native_activity;
+#[cfg(feature = "native-activity")]
+use native_activity as activity_impl;
+
+#[cfg(feature = "test")]
+pub mod test_activity;
+#[cfg(feature = "test")]
+use test_activity as activity_impl;
+
+pub use activity_impl::input;
+
+mod poll;
+use poll::GlueBackend;
+
+mod native_window;
+pub use native_window::{NativeWindowHandle, WindowTerminatedError};
+
+mod lifecycle;
+pub use lifecycle::LifecycleState;
+
+mod configuration;
+pub use configuration::ConfigurationDiff;
+
+mod insets;
+pub use insets::{InsetType, WindowInsets};
+
+mod trim_memory;
+pub use trim_memory::TrimMemoryLevel;
+
+mod assets;
+pub use assets::{AssetEntry, AssetFile, AssetLoad, AssetMap, Assets};
+
+mod fd_sources;
+pub use fd_sources::FdToken;
+
+mod timer;
+pub use timer::TimerId;
+
+mod user_events;
+pub use user_events::{EventSender, UserEvent};
+
+mod choreographer;
+
+pub mod logger;
+
+#[cfg(not(feature = "test"))]
+mod crash;
+
+#[cfg(feature = "stdio-redirect")]
+pub mod stdio;
+
+pub mod trace;
+
+mod state_spill;
+
+mod finish;
+pub use finish::ActivityFinisher;
+
+#[cfg(feature = "serde")]
+mod saved_state;
+#[cfg(feature = "serde")]
+pub use saved_state::{SavedState, SavedStateError};
+
+pub mod recording;
+use recording::{RecordedEvent, Recording};
+
+#[cfg(feature = "async")]
+pub mod async_events;
+
+// The application's `android_main` function is passed the `AndroidApp`
+// directly but we also provide a side-band way to access the app via
+// `android_app()`.
+//
+// Note: for winit if we removed the `android_app()` getter then
+// apps would have to explicitly pass the AndroidApp via an
+// android specific event loop builder api /
+// PlatformSpecificEventLoopAttributes - so having this getter
+// also helps keep simple winit usage portable.
+//
+// Since `AndroidApp` is not `Send` then the app is only made
+// available to the thread that runs `android_main`, for as long
+// as `android_main` is running.
+#[derive(Debug)]
+enum CurrentApp {
+    NotStarted,
+    Running(AndroidApp),
+    Finished,
+}
+
+thread_local! {
+    static ANDROID_APP: RefCell<CurrentApp> = const { RefCell::new(CurrentApp::NotStarted) };
+}
+
+// Note: unlike in ndk-glue this has signed components (consistent
+// with Android's ARect) which generally allows for representing
+// rectangles with a negative/off-screen origin. Even though this
+// is currently just used to represent the content rect (that probably
+// wouldn't have any negative components) we keep the generality
+// since this is a primitive type that could potentially be used
+// for more things in the future.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Rect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+/// The size of a window, in pixels
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Size {
+    pub width: i32,
+    pub height: i32,
+}
+
+// The only time it's safe to update the android_app->savedState pointer is
+// while handling a SaveState event, so this API is only exposed for those
+// events...
+#[derive(Debug)]
+pub struct StateSaver<'a> {
+    // `None` while replaying a recording, in which case the state is discarded
+    inner: Option<activity_impl::StateSaver<'a>>,
+}
+
+impl<'a> StateSaver<'a> {
+    pub(crate) fn new(inner: activity_impl::StateSaver<'a>) -> Self {
+        Self { inner: Some(inner) }
+    }
+
+    pub(crate) fn replayed() -> Self {
+        Self { inner: None }
+    }
+
+    /// Stores the application's state, to be passed back via a [`StateLoader`] if
+    /// the Activity is recreated
+    ///
+    /// State that's larger than the threshold set via
+    /// [`AndroidApp::set_saved_state_spill_threshold()`] is written to a file in the
+    /// application's internal data directory and only a small handle is stored
+    /// with the Activity.
+    pub fn store(&self, state: &[u8]) {
+        if let Some(inner) = &self.inner {
+            match inner.app.state_spill().spill(state) {
+                Some(handle) => inner.store(&handle),
+                None => inner.store(state),
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+enum StateLoaderInner<'a> {
+    // Spilled state is read back from a file, so it's cached for any subsequent
+    // loads
+    Glue(activity_impl::StateLoader<'a>, OnceCell<Option<Vec<u8>>>),
+    Replayed(Option<&'a [u8]>),
+}
+
+#[derive(Debug)]
+pub struct StateLoader<'a> {
+    inner: StateLoaderInner<'a>,
+}
+impl<'a> StateLoader<'a> {
+    pub(crate) fn new(inner: activity_impl::StateLoader<'a>) -> Self {
+        Self { inner: StateLoaderInner::Glue(inner, OnceCell::new()) }
+    }
+
+    pub(crate) fn replayed(state: Option<&'a [u8]>) -> Self {
+        Self { inner: StateLoaderInner::Replayed(state) }
+    }
+
+    pub fn load(&self) -> Option<Vec<u8>> {
+        match &self.inner {
+            StateLoaderInner::Glue(inner, loaded) => loaded
+                .get_or_init(|| inner.load().and_then(|state| inner.app.state_spill().unspill(state)))
+                .clone(),
+            StateLoaderInner::Replayed(state) => state.map(|state| state.to_vec()),
+        }
+    }
+}
+
+// TODO: make more of these into non_exhaustive structs so it's possible to
+// extend what data is passed to each event without breaking the API..
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum MainEvent<'a> {
+
+    // XXX: No need to expose for now, and isn't applicable with GameActivity
+    // Command from main thread: the input queue has changed.
+    // Note: since the internal `AInputQueue` is not exposed directly, applications
+    // won't typically need to react to this.
+    //InputQueueChanged,
+
+    /// Command from main thread: a new [`NativeWindow`](ndk::native_window::NativeWindow) is ready for use.  Upon
+    /// receiving this command, [`AndroidApp::native_window()`] will return the new window
+    #[non_exhaustive]
+    InitWindow { },
+
+    /// Command from main thread: the existing [`NativeWindow`](ndk::native_window::NativeWindow) needs to be
+    /// terminated.  Upon receiving this command, [`AndroidApp::native_window()`] still
+    /// returns the existing window; after returning from the [`AndroidApp::poll_events()`]
+    /// callback then [`AndroidApp::native_window()`] will return `None` and any existing
+    /// [`NativeWindowHandle`] will be invalidated.
+    #[non_exhaustive]
+    TerminateWindow {},
+
+    /// Command from main thread: the current [`NativeWindow`](ndk::native_window::NativeWindow) has been resized.
+    /// Please redraw with its new size.
+    ///
+    /// `old_size` is the size of the window when it was last reported, by
+    /// [`MainEvent::InitWindow`] or a previous `WindowResized` event, and
+    /// `new_size` is its size at the time it was resized.
+    #[non_exhaustive]
+    WindowResized { old_size: Size, new_size: Size },
+
+    /// Command from main thread: the current [`NativeWindow`](ndk::native_window::NativeWindow) needs to be redrawn.
+    /// You should redraw the window before the [`AndroidApp::poll_events()`]
+    /// callback returns in order to avoid transient drawing glitches.
+    #[non_exhaustive]
+    RedrawNeeded {},
+
+    /// Command from main thread: the content area of the window has changed,
+    /// such as from the soft input window being shown or hidden.  The new
+    /// content rect is also returned by [`AndroidApp::content_rect()`] while
+    /// handling this event.
+    #[non_exhaustive]
+    ContentRectChanged { rect: Rect },
+
+    /// Command from main thread: the app's activity window has gained
+    /// input focus.
+    GainedFocus,
+
+    /// Command from main thread: the app's activity window has lost
+    /// input focus.
+    LostFocus,
+
+    /// Command from main thread: the current device configuration has changed.
+    /// `new` is the same [Configuration] that's returned by [`AndroidApp::config()`]
+    /// from now on.
+    ///
+    /// `old` is the configuration that was last reported, at startup or by a
+    /// previous `ConfigChanged` event, and `diff` is the set of fields that differ
+    /// between the two. If the configuration changes several times before the
+    /// application handles this event then all of the changes are reported
+    /// together and the following events may have an empty `diff`.
+    ///
+    /// While a [`Recording`] is replayed only `diff` is recorded, and both `old`
+    /// and `new` are the current configuration, so `diff` should be used to
+    /// check which fields changed.
+    #[non_exhaustive]
+    ConfigChanged { diff: ConfigurationDiff, old: Configuration, new: Configuration },
+
+    /// Command from main thread: the system is running low on memory.
+    /// Try to reduce your memory use, according to the `level` of memory
+    /// pressure.
+    ///
+    /// Note: `NativeActivity` only reports [`TrimMemoryLevel::Complete`]
+    #[non_exhaustive]
+    LowMemory { level: TrimMemoryLevel },
+
+    /// Command from main thread: the app's activity has been started.
+    Start,
+
+    /// Command from main thread: the app's activity has been resumed.
+    #[non_exhaustive]
+    Resume { loader: StateLoader<'a> },
+
+    /// Command from main thread: the app should generate a new saved state
+    /// for itself, to restore from later if needed.  If you have saved state,
+    /// allocate it with malloc and place it in android_app.savedState with
+    /// the size in android_app.savedStateSize.  The will be freed for you
+    /// later.
+    #[non_exhaustive]
+    SaveState { saver: StateSaver<'a> },
+
+    /// Command from main thread: the app's activity has been paused.
+    Pause,
+
+    /// Command from main thread: the app's activity has been stopped.
+    Stop,
+
+    /// Command from main thread: the app's activity is being destroyed,
+    /// and waiting for the app thread to clean up and exit before proceeding.
+    Destroy,
+
+    /// Command from main thread: the app's insets have changed, with the
+    /// new `insets` for each [`InsetType`].
+    ///
+    /// Note: this is currently only delivered by the `GameActivity` backend
+    #[non_exhaustive]
+    InsetsChanged { insets: WindowInsets },
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PollEvent<'a> {
+    Wake,
+    Timeout,
+    Main(MainEvent<'a>),
+
+    /// A file descriptor that was registered via [`AndroidApp::register_fd()`]
+    /// is ready, with the `events` that occurred
+    #[non_exhaustive]
+    Fd { token: FdToken, events: FdEvent },
+
+    /// A timer that was added via [`AndroidApp::add_timer()`] has expired
+    Timer(TimerId),
+
+    /// An event that was sent via an [`EventSender`] from
+    /// [`AndroidApp::create_event_channel()`]
+    User(UserEvent),
+
+    /// A frame that was requested via [`AndroidApp::request_frame()`] is
+    /// starting to be rendered by the display
+    ///
+    /// `frame_time_nanos` is the time that the frame started, relative to the
+    /// `CLOCK_MONOTONIC` clock (the same as `System.nanoTime()`). The `vsync_id`
+    /// identifies the frame's vsync for APIs such as
+    /// `ASurfaceTransaction_setFrameTimeline`, and is only available on
+    /// Android 13 or later.
+    #[non_exhaustive]
+    Frame { frame_time_nanos: i64, vsync_id: Option<i64> },
+
+    Error
+}
+
+#[derive(Clone)]
+pub struct AndroidAppWaker {
+    // The looper pointer is owned by the android_app and effectively
+    // has a 'static lifetime, and the ALooper_wake C API is thread
+    // safe, so this can be cloned safely and is send + sync safe
+    looper: NonNull<ALooper>
+}
+unsafe impl Send for AndroidAppWaker {}
+unsafe impl Sync for AndroidAppWaker {}
+
+impl AndroidAppWaker {
+    pub fn wake(&self) {
+        unsafe { ALooper_wake(self.looper.as_ptr()); }
+    }
+}
+
+// The state is only ever used by the application's main thread, so `AndroidApp`
+// is neither `Send` nor `Sync`
+#[derive(Debug, Clone)]
+pub struct AndroidApp {
+    inner: Rc<activity_impl::AndroidAppInner>
+}
+
+impl AndroidApp {
+
+    /// Calls [`ALooper_pollAll`] on the looper associated with this AndroidApp as well
+    /// as processing any events (such as lifecycle events) via the given `callback`.
+    ///
+    /// It's important to use this API for polling, and not call [`ALooper_pollAll`] directly since
+    /// some events require pre- and post-processing either side of the callback. For correct
+    /// behavior events should be handled immediately, before returning from the callback and
+    /// not simply queued for batch processing later. For example the existing [`NativeWindow`](ndk::native_window::NativeWindow)
+    /// is accessible during a [`MainEvent::TerminateWindow`] callback and will be
+    /// set to `None` once the callback returns, and this is also synchronized with the Java
+    /// main thread. The [`MainEvent::SaveState`] event is also synchronized with the
+    /// Java main thread.
+    ///
+    /// [`ALooper_pollAll`]: ndk_sys::ALooper_pollAll
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn poll_events<F>(&self, timeout: Option<Duration>, mut callback: F)
+        where F: FnMut(PollEvent)
+    {
+        if self.is_replaying() {
+            self.poll_replayed_events(timeout, callback);
+            return;
+        }
+
+        self.inner.poll_events(timeout, |event| self.dispatch_event(event, &mut callback));
+    }
+
+    // Passes a real event to the application, after updating the lifecycle
+    // state and recording it
+    fn dispatch_event<F>(&self, event: PollEvent, callback: &mut F)
+        where F: FnMut(PollEvent)
+    {
+        if let PollEvent::Main(main_event) = &event {
+            self.inner.lifecycle().update(main_event);
+        }
+        let mut recording = self.inner.recording().borrow_mut();
+        if recording.is_recording() {
+            recording.record(&RecordedEvent::from_poll_event(self, &event));
+        }
+        drop(recording);
+        callback(event)
+    }
+
+    // While replaying, the glue layer's commands are still handled but the
+    // application only sees the replayed events, plus any real events that it
+    // asked for itself (fd, timer, user and frame events) and real `SaveState`
+    // events, so that its real state is still saved. A real `TerminateWindow`
+    // or `Destroy` ends the replay, since the application must see the loss of
+    // its window or Activity.
+    //
+    // The lifecycle state always tracks the real Activity, so it's updated for
+    // the real events that are hidden, but not for replayed events.
+    fn poll_replayed_events<F>(&self, timeout: Option<Duration>, mut callback: F)
+        where F: FnMut(PollEvent)
+    {
+        let wait = self.inner.recording().borrow_mut().time_until_next_replay();
+        let wait = match wait {
+            Some(wait) => wait,
+            None => {
+                log::info!("Finished replaying recorded events");
+                return self.poll_events(timeout, callback);
+            }
+        };
+        let wait = timeout.map_or(wait, |timeout| timeout.min(wait));
+        self.inner.poll_events(Some(wait), |event| match event {
+            PollEvent::Main(MainEvent::TerminateWindow { .. } | MainEvent::Destroy) => {
+                log::info!("Stopped replaying recorded events after a real {event:?} event");
+                self.inner.recording().borrow_mut().stop_replay();
+                self.dispatch_event(event, &mut callback);
+            }
+            PollEvent::Fd { .. } | PollEvent::Timer(_) | PollEvent::User(_) | PollEvent::Frame { .. }
+            | PollEvent::Main(MainEvent::SaveState { .. }) => self.dispatch_event(event, &mut callback),
+            PollEvent::Main(main_event) => self.inner.lifecycle().update(&main_event),
+            _ => {}
+        });
+
+        let event = self.inner.recording().borrow_mut().next_replayed_event();
+        if let Some(event) = event.as_ref().and_then(|event| event.to_poll_event(self)) {
+            callback(event);
+        }
+    }
+
+    /// Creates a means to wake up the main loop while it is blocked waiting for
+    /// events within [`AndroidApp::poll_events()`].
+    ///
+    /// Internally this uses [`ALooper_wake`] on the looper associated with this
+    /// [AndroidApp].
+    ///
+    /// # Safety
+    /// This API can be used from any thread
+    pub fn create_waker(&self) -> AndroidAppWaker {
+        self.inner.create_waker()
+    }
+
+    /// Asks the Java main thread to finish the Activity, like `Activity.finish()`
+    ///
+    /// This returns immediately, and the application then receives the usual
+    /// lifecycle events up to and including [`MainEvent::Destroy`], so it should
+    /// keep calling [`AndroidApp::poll_events()`] until then. The Activity is also
+    /// finished automatically when `android_main` returns (or panics), unless it
+    /// was already being destroyed.
+    ///
+    /// `AndroidApp` isn't [`Send`], so this can only be called on the
+    /// application's main thread. Other threads must finish the Activity via an
+    /// [`ActivityFinisher`] from [`AndroidApp::create_finisher()`].
+    pub fn finish(&self) {
+        // Forwarded so that finishing behaves the same on every thread
+        self.create_finisher().finish();
+    }
+
+    /// Creates a means to finish the Activity from any thread, like
+    /// [`AndroidApp::finish()`]
+    ///
+    /// # Safety
+    /// This API can be used from any thread
+    pub fn create_finisher(&self) -> ActivityFinisher {
+        ActivityFinisher::new(self.inner.finisher().clone())
+    }
+
+    /// Registers a file descriptor (such as a socket, pipe or eventfd) with the
+    /// looper that's polled by [`AndroidApp::poll_events()`]
+    ///
+    /// Whenever any of the `interest` events occur (only
+    /// [`FdEvent::INPUT`] and [`FdEvent::OUTPUT`] are meaningful, while errors and
+    /// hangups are always reported) `poll_events()` delivers a [`PollEvent::Fd`]
+    /// with the returned token. The looper is level-triggered, so the event will
+    /// be delivered repeatedly until the application handles it, such as by
+    /// reading all the available data.
+    ///
+    /// Registering the same fd again replaces the previous registration and the
+    /// previous token will no longer be used. The glue layer's own file
+    /// descriptors (such as the pipe that delivers [`MainEvent`]s) can't be
+    /// registered, and result in an [`std::io::ErrorKind::InvalidInput`] error.
+    ///
+    /// # Safety
+    /// `fd` must be an open file descriptor that's owned by the application and
+    /// it must stay open until it's unregistered via
+    /// [`AndroidApp::unregister_fd()`], or else the looper would poll whichever
+    /// file the fd number is reused for.
+    ///
+    /// This API must only be called from the applications main thread
+    pub unsafe fn register_fd(&self, fd: RawFd, interest: FdEvent) -> std::io::Result<FdToken> {
+        if self.inner.is_glue_fd(fd) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Can't register the glue layer's fd {fd}"),
+            ));
+        }
+        self.inner.poll_state().fd_sources().register(fd, interest)
+    }
+
+    /// Unregisters a file descriptor that was registered via [`AndroidApp::register_fd()`]
+    ///
+    /// No further [`PollEvent::Fd`] events will be delivered for the token, even
+    /// if the looper had already queued an event for it. Returns `false` if the
+    /// token isn't currently registered.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn unregister_fd(&self, token: FdToken) -> bool {
+        self.inner.poll_state().fd_sources().unregister(token)
+    }
+
+    /// Adds a timer that will be delivered as a [`PollEvent::Timer`] by
+    /// [`AndroidApp::poll_events()`] once `delay` has elapsed, and then every
+    /// `delay` if `repeat` is `true`
+    ///
+    /// Timers are backed by a `timerfd` that's registered with the looper, so
+    /// they are independent of the `timeout` passed to `poll_events()`. If the
+    /// application doesn't poll for events in time then the missed expirations
+    /// of a repeating timer are coalesced into a single event.
+    ///
+    /// A timer stays registered until it's cancelled via
+    /// [`AndroidApp::cancel_timer()`], even if it was a one-shot timer that has
+    /// already expired, so that it can be rescheduled.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn add_timer(&self, delay: Duration, repeat: bool) -> std::io::Result<TimerId> {
+        self.inner.poll_state().fd_sources().add_timer(delay, repeat)
+    }
+
+    /// Replaces the schedule of a timer that was added via [`AndroidApp::add_timer()`]
+    ///
+    /// The timer will next expire once `delay` has elapsed, from now, and any
+    /// expiration that hasn't been delivered yet is discarded. Returns an error
+    /// of kind [`NotFound`](std::io::ErrorKind::NotFound) if the timer has been
+    /// cancelled.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn reschedule_timer(&self, id: TimerId, delay: Duration, repeat: bool) -> std::io::Result<()> {
+        self.inner.poll_state().fd_sources().reschedule_timer(id, delay, repeat)
+    }
+
+    /// Cancels a timer that was added via [`AndroidApp::add_timer()`]
+    ///
+    /// No further [`PollEvent::Timer`] events will be delivered for the timer.
+    /// Returns `false` if the timer had already been cancelled.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn cancel_timer(&self, id: TimerId) -> bool {
+        self.inner.poll_state().fd_sources().cancel_timer(id)
+    }
+
+    /// Creates a channel for sending events of type `T` from any thread to the
+    /// application's main loop
+    ///
+    /// Each event that's sent via the returned [`EventSender`] wakes up the
+    /// looper and is delivered by [`AndroidApp::poll_events()`] as a
+    /// [`PollEvent::User`], from which the event can be taken via
+    /// [`UserEvent::downcast()`]. Events from all channels are delivered in the
+    /// order they were sent, one per `PollEvent`.
+    ///
+    /// Unlike [`AndroidAppWaker::wake()`], this makes it possible for the main
+    /// loop to know why it was woken up.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn create_event_channel<T: Send + 'static>(&self) -> std::io::Result<EventSender<T>> {
+        self.inner.poll_state().fd_sources().user_events().map(EventSender::new)
+    }
+
+    /// Requests a [`PollEvent::Frame`] for the next display frame, via the
+    /// [`AChoreographer`](https://developer.android.com/ndk/reference/group/choreographer)
+    ///
+    /// Like the Java `Choreographer`, each request results in a single frame
+    /// event, so an application that renders continuously should request the
+    /// next frame each time it handles a frame, while an application that renders
+    /// on demand can stop requesting frames while idle instead of having to poll
+    /// with a timeout. Multiple requests before the next frame are coalesced.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn request_frame(&self) -> std::io::Result<()> {
+        self.inner.poll_state().fd_sources().frame_callbacks()?.request();
+        Ok(())
+    }
+
+    /// Cancels a frame that was requested via [`AndroidApp::request_frame()`]
+    ///
+    /// No [`PollEvent::Frame`] will be delivered until another frame is
+    /// requested.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn cancel_frame(&self) {
+        self.inner.poll_state().fd_sources().cancel_frame();
+    }
+
+    /// Queries the current [`NativeWindow`](ndk::native_window::NativeWindow) for the application.
+    ///
+    /// This will only return `Some(window)` between
+    /// [`MainEvent::InitWindow`] and [`MainEvent::TerminateWindow`]
+    /// events.
+    ///
+    /// The returned handle can be kept for as long as the window is needed but
+    /// it will be invalidated after the application handles
+    /// [`MainEvent::TerminateWindow`].
+    pub fn native_window(&self) -> Option<NativeWindowHandle> {
+        self.inner.poll_state().native_window()
+    }
+
+    /// Returns `true` if the application currently has a window
+    ///
+    /// This is equivalent to checking [`AndroidApp::native_window()`], so it
+    /// remains `true` until after [`MainEvent::TerminateWindow`] has been handled.
+    pub fn has_window(&self) -> bool {
+        self.inner.poll_state().native_window().is_some()
+    }
+
+    /// Queries the current [`LifecycleState`] of the application's Activity
+    ///
+    /// The state is updated before each lifecycle event is passed to the
+    /// [`AndroidApp::poll_events()`] callback, so for example the state is
+    /// already [`LifecycleState::Resumed`] while handling [`MainEvent::Resume`].
+    ///
+    /// A warning is logged if the glue layer ever delivers a lifecycle event
+    /// that isn't valid for the current state.
+    ///
+    /// This is always the state of the real Activity, so it isn't affected by
+    /// the lifecycle events of a [`Recording`] that's being replayed, and it
+    /// still follows the real lifecycle events that are hidden by the replay.
+    pub fn lifecycle_state(&self) -> LifecycleState {
+        self.inner.lifecycle().state()
+    }
+
+    /// Returns `true` if the Activity is visible, between [`MainEvent::Start`]
+    /// and [`MainEvent::Stop`]
+    pub fn is_visible(&self) -> bool {
+        self.lifecycle_state() >= LifecycleState::Started
+    }
+
+    /// Returns `true` if the Activity's window has input focus, between
+    /// [`MainEvent::GainedFocus`] and [`MainEvent::LostFocus`]
+    pub fn is_focused(&self) -> bool {
+        self.inner.lifecycle().is_focused()
+    }
+
+    /// Sets the size (in bytes) above which saved state is spilled to a file
+    ///
+    /// Saved state is passed to the Activity in a `Bundle` that must fit within the
+    /// Binder transaction limit, and a large bundle can kill the process with a
+    /// `TransactionTooLargeException`. State that's larger than this threshold is
+    /// instead written to a file in the application's internal data directory,
+    /// which is read back by [`StateLoader::load()`]. Each file is kept until the
+    /// next time state is spilled, in case the Activity restores the same state
+    /// again, and files that are no longer needed are deleted the next time the
+    /// application's process starts.
+    ///
+    /// The default threshold is 64 KiB and `None` disables spilling.
+    pub fn set_saved_state_spill_threshold(&self, threshold: Option<usize>) {
+        self.inner.state_spill().set_threshold(threshold)
+    }
+
+    /// Returns a deep copy of this application's [`Configuration`]
+    pub fn config(&self) -> Configuration {
+        self.inner.poll_state().config()
+    }
+
+    /// Queries the current content rectangle of the window; this is the area where the
+    /// window's content should be placed to be seen by the user.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn content_rect(&self) -> Rect {
+        self.inner.content_rect()
+    }
+
+    /// Queries the Asset Manager instance for the application.
+    ///
+    /// Use this to access binary assets bundled inside your application's .apk file.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn asset_manager(&self) -> AssetManager {
+        self.inner.asset_manager()
+    }
+
+    /// The path to the application's internal data directory
+    ///
+    /// This is private to the application and is where
+    /// [`StateSaver::store()`] spills large saved state. It's also used for `HOME`
+    /// with the `env-paths` feature.
+    pub fn internal_data_path(&self) -> Option<PathBuf> {
+        self.inner.internal_data_path()
+    }
+
+    /// The path to the application's external data directory
+    ///
+    /// This is on shared storage, which may not be available (e.g. if it's mounted
+    /// on a computer via USB).
+    pub fn external_data_path(&self) -> Option<PathBuf> {
+        self.inner.external_data_path()
+    }
+
+    /// The path to the directory containing the application's OBB (opaque binary
+    /// blob) expansion files, if any
+    pub fn obb_path(&self) -> Option<PathBuf> {
+        self.inner.obb_path()
+    }
+
+    /// The API level of the device that the application is running on
+    pub fn sdk_version(&self) -> u32 {
+        self.inner.sdk_version()
+    }
+
+    /// Returns the application's [`Assets`], for reading files that are bundled
+    /// inside the application's .apk file by path
+    ///
+    /// With the `test` backend, assets are instead read from a local directory
+    /// (see [`TestActivity::set_assets_dir()`](crate::test_activity::TestActivity::set_assets_dir)).
+    pub fn assets(&self) -> Assets {
+        self.inner.assets()
+    }
+
+    /// Process all currently buffered input events
+    ///
+    /// Input events aren't delivered via [`AndroidApp::poll_events()`] and it's
+    /// expected that applications explicitly check for events (e.g. as part of
+    /// preparing a new frame to render). I.e. this is a pull model, not a push
+    /// model; input events aren't immediately delivered as they arrive. One benefit
+    /// of this design is that detailed input events can be buffered and processed
+    /// more efficiently as a batch at a time that's most appropriate for your
+    /// application.
+    ///
+    /// With the `NativeActivity` backend, new input wakes up
+    /// [`AndroidApp::poll_events()`] with a [`PollEvent::Wake`], which is only
+    /// delivered once until this function is next called. With the
+    /// `GameActivity` backend your application won't be woken up purely due to
+    /// input events and so you need some other external trigger to ensure input
+    /// is checked periodically.
+    ///
+    /// With the `GameActivity` backend input events are captured asynchronously
+    /// (within the Java main thread) and buffered so that the application can
+    /// safely process the events it has taken from the buffer while further input
+    /// events may continue to be accumulated. The buffer grows as needed, up to a
+    /// limit that can be set via `AndroidAppExt::set_max_buffered_input_events()`.
+    /// Historical samples aren't available, so each motion event only carries its
+    /// most recent sample.
+    ///
+    /// Returns the number of events that were dropped since the last call because
+    /// the buffer was full, which is always zero with the `NativeActivity` backend
+    /// since its events are queued by the `InputQueue` instead.
+    ///
+    /// # Safety
+    /// This API must only be called from the applications main thread
+    pub fn input_events<F>(&self, mut callback: F) -> usize
+        where F: FnMut(&input::InputEvent)
+    {
+        if self.is_replaying() {
+            // Real input is discarded while replaying
+            self.inner.input_events(|_| {});
+            #[cfg(any(feature = "game-activity", feature = "test"))]
+            for event in self.inner.recording().borrow_mut().take_replayed_input() {
+                callback(&event);
+            }
+            return 0;
+        }
+
+        #[cfg(any(feature = "game-activity", feature = "test"))]
+        if self.inner.recording().borrow().is_recording() {
+            return self.inner.input_events(|event| {
+                self.inner.recording().borrow_mut().record(&RecordedEvent::Input(Box::new(event.clone())));
+                callback(event)
+            });
+        }
+
+        self.inner.input_events(&mut callback)
+    }
+
+    /// Starts recording all the events that are delivered to the application to
+    /// the given file, which can later be loaded as a [`Recording`]
+    ///
+    /// Any previous recording is stopped first. See the [`recording`] module for
+    /// more details.
+    ///
+    /// With the `NativeActivity` backend input events aren't recorded, and a
+    /// warning is logged when recording starts.
+    pub fn start_recording<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        #[cfg(feature = "native-activity")]
+        log::warn!("Input events can't be recorded with the NativeActivity backend, so the recording will only include non-input events");
+        self.inner.recording().borrow_mut().start_recording(path.as_ref())
+    }
+
+    /// Stops recording events, flushing any buffered events to the file
+    pub fn stop_recording(&self) -> std::io::Result<()> {
+        self.inner.recording().borrow_mut().stop_recording()
+    }
+
+    /// Starts replaying a [`Recording`], so that [`AndroidApp::poll_events()`]
+    /// and [`AndroidApp::input_events()`] deliver the recorded events, with the
+    /// same timing, until the end of the recording, or until the real window or
+    /// Activity is destroyed
+    ///
+    /// See the [`recording`] module for more details.
+    pub fn start_replay(&self, recording: Recording) {
+        self.inner.recording().borrow_mut().start_replay(recording);
+    }
+
+    /// Returns `true` while a [`Recording`] is being replayed
+    pub fn is_replaying(&self) -> bool {
+        self.inner.recording().borrow().is_replaying()
+    }
+
+    /// Returns a [`Stream`](futures_core::Stream) of owned application events
+    ///
+    /// The stream needs to be driven by an [`async_events::Executor`] running on
+    /// the applications main thread. See the [`async_events`] module for more
+    /// details.
+    #[cfg(feature = "async")]
+    pub fn events(&self) -> async_events::EventStream {
+        async_events::EventStream::new(self.clone())
+    }
+}
+
+/// The error returned by [`android_app()`] when there's no [`AndroidApp`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AndroidAppError {
+    /// Not called on the thread that runs `android_main`, or called before
+    /// `android_main` starts
+    NotRunning,
+
+    /// `android_main` has returned, so the Activity is being finished (or has
+    /// already been destroyed)
+    Finished,
+}
+
+impl std::fmt::Display for AndroidAppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AndroidAppError::NotRunning => write!(f, "Not called on the thread that's running android_main"),
+            AndroidAppError::Finished => write!(f, "android_main has returned"),
+        }
+    }
+}
+
+impl std::error::Error for AndroidAppError {}
+
+/// Gets the [`AndroidApp`] that was passed to `android_main`
+///
+/// Returns an error if not called on the thread that's running `android_main`,
+/// or if called before `android_main` starts or after it has returned.
+pub fn android_app() -> Result<AndroidApp, AndroidAppError> {
+    ANDROID_APP.with(|app| match &*app.borrow() {
+        CurrentApp::NotStarted => Err(AndroidAppError::NotRunning),
+        CurrentApp::Running(app) => Ok(app.clone()),
+        CurrentApp::Finished => Err(AndroidAppError::Finished),
+    })
+}
+
+// Once `android_main` returns, or unwinds, this clears the `android_app()`
+// state and finishes the Activity, unless it's already being destroyed. The
+// Activity can be freed as soon as the application thread exits, so finishing
+// is disabled from then on.
+struct AndroidAppGuard(AndroidApp);
+
+impl Drop for AndroidAppGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "async")]
+        async_events::clear_event_queue();
+        ANDROID_APP.with(|app| *app.borrow_mut() = CurrentApp::Finished);
+
+        if self.0.lifecycle_state() != LifecycleState::Destroyed {
+            self.0.finish();
+        }
+        self.0.inner.finisher().clear();
+    }
+}
+
+// Runs the application's main function with `app` made available
+// via `android_app()`
+fn run_main_with_app<F>(app: AndroidApp, main: F)
+    where F: FnOnce(AndroidApp)
+{
+    ANDROID_APP.with(|current| *current.borrow_mut() = CurrentApp::Running(app.clone()));
+    let _guard = AndroidAppGuard(app.clone());
+    main(app);
+}
+
+/// Declares the application's main function, which is called on a dedicated
+/// thread once the Activity has been created
+///
+/// The function must be a `fn(AndroidApp)`, which is checked at compile time:
+///
+/// ```no_run
+/// use android_activity::AndroidApp;
+///
+/// fn android_main(app: AndroidApp) {
+///     // ...
+/// }
+///
+/// android_activity::main!(android_main);
+/// ```
+///
+/// ```compile_fail
+/// fn android_main() {}
+///
+/// android_activity::main!(android_main);
+/// ```
+///
+/// The glue layer finds the main function via a symbol that's only defined by
+/// this macro, so an application that doesn't use it (such as one that still
+/// exports a `#[no_mangle]` `android_main` function) fails to link instead of
+/// being called with the wrong signature.
+#[macro_export]
+macro_rules! main {
+    ($main:expr) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub fn _android_activity_main(app: $crate::AndroidApp) {
+            let main: fn($crate::AndroidApp) = $main;
+            main(app)
+        }
+    };
+}
+
+// Defined by `main!()`, which checks the signature of the application's main
+// function. It's called via the Rust ABI, since the application is always
+// compiled together with this crate.
+#[cfg(not(feature = "test"))]
+extern "Rust" {
+    fn _android_activity_main(app: AndroidApp);
+}
+
+// Converts one of an Activity's (possibly null) path strings
+#[cfg(not(feature = "test"))]
+unsafe fn path_from_ptr(path: *const raw::c_char) -> Option<PathBuf> {
+    if path.is_null() {
+        None
+    } else {
+        Some(PathBuf::from(std::ffi::OsStr::from_bytes(CStr::from_ptr(path).to_bytes())))
+    }
+}
+
+// Points `HOME` at the internal data directory and `TMPDIR` at the
+// application's cache directory (next to the internal data directory), so that
+// `std::env::temp_dir()` and crates like `dirs` find writable directories.
+//
+// This is done before any of our own threads are started (such as for
+// redirecting stdio) or `android_main` runs, since other threads could
+// otherwise be reading the environment at the same time. No logger has been
+// installed yet, so any problems are written to logcat directly.
+#[cfg(all(feature = "env-paths", not(feature = "test")))]
+fn set_env_paths(app: &AndroidApp) {
+    const TAG: &CStr = c"android_activity";
+    let home = match app.internal_data_path() {
+        Some(home) => home,
+        None => {
+            logger::write(log::Level::Warn, TAG, "Not setting HOME or TMPDIR, since there's no internal data directory");
+            return;
+        }
+    };
+    let tmp = home.parent().map_or_else(|| home.join("tmp"), |parent| parent.join("cache"));
+    if let Err(err) = std::fs::create_dir_all(&tmp) {
+        logger::write(log::Level::Warn, TAG, &format!("Failed to create {tmp:?} for TMPDIR: {err}"));
+    }
+    std::env::set_var("HOME", &home);
+    std::env::set_var("TMPDIR", &tmp);
+}
+
+// The common tail end of each backend's entry point that's run on the
+// dedicated application thread spawned by the glue layer, once the backend
+// has initialized its `AndroidAppInner` state.
+#[cfg(not(feature = "test"))]
+unsafe fn run_android_main(app: AndroidApp, vm: *mut std::ffi::c_void, activity: *mut std::ffi::c_void) {
+    #[cfg(feature = "env-paths")]
+    set_env_paths(&app);
+
+    #[cfg(feature = "logger")]
+    logger::init();
+
+    #[cfg(feature = "stdio-redirect")]
+    let redirect = stdio::Redirect::start()
+        .map_err(|err| log::error!("Failed to redirect stdout and stderr to logcat: {err}"))
+        .ok();
+
+    ndk_context::initialize_android_context(vm, activity);
+
+    crash::install_panic_hook(app.internal_data_path().map(|dir| dir.join("crash-reports")));
+
+    // A panic mustn't unwind into the glue layer, and the Activity has already
+    // been finished either way (the panic has already been logged by the panic
+    // hook). Once this returns the glue layer is destroyed and stops waiting for
+    // the Java main thread's remaining commands to be handled, so finishing
+    // can't block the Activity's lifecycle callbacks.
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| run_main_with_app(app, |app| _android_activity_main(app))));
+
+    #[cfg(feature = "stdio-redirect")]
+    if let Some(redirect) = redirect {
+        redirect.finish();
+    }
+
+    ndk_context::release_android_context();
+}
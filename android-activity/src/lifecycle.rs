@@ -0,0 +1,84 @@
+//! Tracks the Activity lifecycle from the events delivered to the application
+
+use log::warn;
+use std::cell::Cell;
+
+use crate::MainEvent;
+
+/// The lifecycle state of the application's Activity
+///
+/// These follow the states of Android's
+/// [`Lifecycle.State`](https://developer.android.com/reference/androidx/lifecycle/Lifecycle.State)
+/// and are ordered so that, for example, `state >= LifecycleState::Started` is
+/// true while the Activity is started or resumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LifecycleState {
+    /// The Activity is being destroyed, after [`MainEvent::Destroy`]
+    Destroyed,
+
+    /// The Activity has been created but isn't started, which is the initial
+    /// state when `android_main` is called and the state after [`MainEvent::Stop`]
+    Created,
+
+    /// The Activity is visible, after [`MainEvent::Start`] or [`MainEvent::Pause`]
+    Started,
+
+    /// The Activity is in the foreground, after [`MainEvent::Resume`]
+    Resumed,
+}
+
+#[derive(Debug)]
+pub(crate) struct Lifecycle {
+    state: Cell<LifecycleState>,
+    focused: Cell<bool>,
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self {
+            state: Cell::new(LifecycleState::Created),
+            focused: Cell::new(false),
+        }
+    }
+}
+
+impl Lifecycle {
+    pub(crate) fn state(&self) -> LifecycleState {
+        self.state.get()
+    }
+
+    pub(crate) fn is_focused(&self) -> bool {
+        self.focused.get()
+    }
+
+    /// Updates the tracked state before `event` is passed to the application
+    pub(crate) fn update(&self, event: &MainEvent) {
+        let (expected, next) = match event {
+            MainEvent::Start => (LifecycleState::Created, LifecycleState::Started),
+            MainEvent::Resume { .. } => (LifecycleState::Started, LifecycleState::Resumed),
+            MainEvent::Pause => (LifecycleState::Resumed, LifecycleState::Started),
+            MainEvent::Stop => (LifecycleState::Started, LifecycleState::Created),
+            MainEvent::Destroy => (LifecycleState::Created, LifecycleState::Destroyed),
+            MainEvent::GainedFocus => {
+                self.focused.set(true);
+                return;
+            }
+            MainEvent::LostFocus => {
+                self.focused.set(false);
+                return;
+            }
+            _ => return,
+        };
+
+        // The glue layer is the authority on the current state, so we still
+        // follow the transition even if it's unexpected
+        let current = self.state.get();
+        if current != expected {
+            warn!("Out of order lifecycle event {event:?} while in state {current:?}, expected state {expected:?}");
+        }
+        self.state.set(next);
+        if next == LifecycleState::Destroyed {
+            self.focused.set(false);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! A logcat backend for the `log` (and optionally `tracing`) facades
+//!
+//! With the `logger` feature, the logger is installed by the glue layer before
+//! `android_main` is called, so applications don't need to depend on a separate
+//! crate like `android_logger`. Otherwise it can be installed via [`init()`].
+//!
+//! Each message is logged with a tag that's chosen by the module that it was
+//! logged from (see [`set_module_tag()`]), which defaults to the name of the
+//! crate. Messages that are longer than logcat's maximum entry size are split
+//! across multiple entries, rather than being truncated.
+//!
+//! With the `tracing` feature, [`layer()`] returns a `tracing_subscriber`
+//! [`Layer`](tracing_subscriber::Layer) that logs `tracing` events in the same
+//! way.
+//!
+//! The maximum level can be adjusted at runtime via [`set_max_level()`].
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_int;
+use std::sync::RwLock;
+
+// `LOGGER_ENTRY_MAX_PAYLOAD`, which has to fit the priority, the tag and the
+// message (each nul terminated)
+const MAX_PAYLOAD_LEN: usize = 4068;
+
+#[derive(Debug)]
+struct Tags {
+    // Overrides the crate name for modules without their own tag
+    default: Option<CString>,
+
+    // (module path, tag) pairs
+    modules: Vec<(String, CString)>,
+}
+
+static TAGS: RwLock<Tags> = RwLock::new(Tags { default: None, modules: Vec::new() });
+
+static LOGGER: Logger = Logger;
+
+const DEFAULT_MAX_LEVEL: LevelFilter = if cfg!(debug_assertions) { LevelFilter::Trace } else { LevelFilter::Info };
+
+// `tracing` events are filtered separately, since the `log` maximum level is
+// `Off` until a logger has been installed
+#[cfg(feature = "tracing")]
+static TRACING_MAX_LEVEL: RwLock<LevelFilter> = RwLock::new(DEFAULT_MAX_LEVEL);
+
+// Returns `s` without any interior nul bytes
+fn to_cstring(s: &str) -> CString {
+    CString::new(s.replace('\0', "\u{fffd}")).unwrap()
+}
+
+/// Installs the logcat logger, if no other logger has been installed
+///
+/// This is done automatically with the `logger` feature. The maximum level
+/// defaults to [`LevelFilter::Info`] in release builds and
+/// [`LevelFilter::Trace`] otherwise.
+pub fn init() {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(DEFAULT_MAX_LEVEL);
+    }
+}
+
+/// Sets the maximum level of messages that are logged, via both `log` and `tracing`
+pub fn set_max_level(level: LevelFilter) {
+    log::set_max_level(level);
+    #[cfg(feature = "tracing")]
+    {
+        *TRACING_MAX_LEVEL.write().unwrap() = level;
+    }
+}
+
+/// Sets the tag for messages from modules that don't have their own tag via
+/// [`set_module_tag()`], instead of the name of their crate
+pub fn set_default_tag(tag: &str) {
+    TAGS.write().unwrap().default = Some(to_cstring(tag));
+}
+
+/// Sets the tag for messages that are logged from `module` and any of its
+/// submodules, such as `"my_app::audio"`
+///
+/// The tag for the most specific matching module is used.
+pub fn set_module_tag(module: &str, tag: &str) {
+    let mut tags = TAGS.write().unwrap();
+    tags.modules.retain(|(path, _)| path != module);
+    tags.modules.push((module.to_string(), to_cstring(tag)));
+}
+
+// The tag for a message with the given target (normally the module path)
+fn tag_for(target: &str) -> CString {
+    let tags = TAGS.read().unwrap();
+    let module_tag = tags.modules.iter()
+        .filter(|(module, _)| {
+            target.strip_prefix(module.as_str()).is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+        .max_by_key(|(module, _)| module.len());
+    match (module_tag, &tags.default) {
+        (Some((_, tag)), _) => tag.clone(),
+        (None, Some(tag)) => tag.clone(),
+        (None, None) => to_cstring(target.split("::").next().unwrap_or(target)),
+    }
+}
+
+// Splits `msg` into chunks of at most `max_len` bytes, preferring to split at
+// a newline
+fn split_message(mut msg: &str, max_len: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    while msg.len() > max_len {
+        let mut end = max_len;
+        while !msg.is_char_boundary(end) {
+            end -= 1;
+        }
+        match msg[..end].rfind('\n') {
+            Some(newline) if newline > 0 => {
+                chunks.push(&msg[..newline]);
+                msg = &msg[newline + 1..];
+            }
+            _ => {
+                chunks.push(&msg[..end]);
+                msg = &msg[end..];
+            }
+        }
+    }
+    chunks.push(msg);
+    chunks
+}
+
+/// Writes `msg` to logcat, split across multiple entries if necessary
+pub(crate) fn write(level: Level, tag: &CStr, msg: &str) {
+    let prio = match level {
+        Level::Error => ndk_sys::android_LogPriority_ANDROID_LOG_ERROR,
+        Level::Warn => ndk_sys::android_LogPriority_ANDROID_LOG_WARN,
+        Level::Info => ndk_sys::android_LogPriority_ANDROID_LOG_INFO,
+        Level::Debug => ndk_sys::android_LogPriority_ANDROID_LOG_DEBUG,
+        Level::Trace => ndk_sys::android_LogPriority_ANDROID_LOG_VERBOSE,
+    };
+    let max_len = MAX_PAYLOAD_LEN.saturating_sub(tag.to_bytes().len() + 3).max(1);
+    let msg: Cow<str> = if msg.contains('\0') { msg.replace('\0', "\u{fffd}").into() } else { msg.into() };
+    for chunk in split_message(&msg, max_len) {
+        let chunk = CString::new(chunk).unwrap();
+        unsafe {
+            ndk_sys::__android_log_write(prio as c_int, tag.as_ptr(), chunk.as_ptr());
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            write(record.level(), &tag_for(record.target()), &record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(feature = "tracing")]
+pub use self::tracing_layer::{layer, LogcatLayer};
+
+#[cfg(feature = "tracing")]
+mod tracing_layer {
+    use std::fmt::{self, Write};
+
+    use log::Level;
+    use tracing_core::field::{Field, Visit};
+    use tracing_core::{Event, Subscriber};
+    use tracing_subscriber::layer::Context;
+    use tracing_subscriber::Layer;
+
+    /// A `tracing_subscriber` layer that logs events to logcat, as returned by [`layer()`]
+    #[derive(Debug, Default)]
+    pub struct LogcatLayer {
+        _private: (),
+    }
+
+    /// Returns a `tracing_subscriber` layer that logs events to logcat
+    ///
+    /// Events are tagged according to their target, like `log` records, and are
+    /// filtered by the maximum level that's set via
+    /// [`set_max_level()`](super::set_max_level). The layer doesn't depend on the
+    /// `log` logger being installed, so until the level is set it defaults to
+    /// [`LevelFilter::Info`](log::LevelFilter::Info) in release builds and
+    /// [`LevelFilter::Trace`](log::LevelFilter::Trace) otherwise, like [`init()`](super::init).
+    pub fn layer() -> LogcatLayer {
+        LogcatLayer::default()
+    }
+
+    // Formats the `message` field followed by any other fields as `name=value`
+    #[derive(Default)]
+    struct MessageVisitor {
+        message: String,
+        fields: String,
+    }
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.message, "{value:?}");
+            } else {
+                let _ = write!(self.fields, " {}={value:?}", field.name());
+            }
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message.push_str(value);
+            } else {
+                let _ = write!(self.fields, " {}={value}", field.name());
+            }
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for LogcatLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+            let level = match *metadata.level() {
+                tracing_core::Level::ERROR => Level::Error,
+                tracing_core::Level::WARN => Level::Warn,
+                tracing_core::Level::INFO => Level::Info,
+                tracing_core::Level::DEBUG => Level::Debug,
+                tracing_core::Level::TRACE => Level::Trace,
+            };
+            if level > *super::TRACING_MAX_LEVEL.read().unwrap() {
+                return;
+            }
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+            visitor.message.push_str(&visitor.fields);
+            super::write(level, &super::tag_for(metadata.target()), &visitor.message);
+        }
+    }
+}
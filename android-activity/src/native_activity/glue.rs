@@ -0,0 +1,344 @@
+//! A Rust implementation of `android_native_app_glue`
+//!
+//! This is the state that's shared between the Java main thread, which delivers
+//! `ANativeActivity` callbacks, and the application's main thread that runs
+//! `android_main`. It follows the same design as the NDK's C glue layer, which
+//! it replaces, but makes the synchronization explicit.
+//!
+//! # Synchronization model
+//!
+//! The Java main thread sends commands (`APP_CMD_*`) to the application thread
+//! by writing single bytes to a pipe, which is monitored by the application's
+//! looper as `LOOPER_ID_MAIN`. Any state that needs to be exchanged along with a
+//! command (such as a new window) is stored in [`GlueState`], which is only ever
+//! accessed while holding the glue's mutex.
+//!
+//! The application thread handles each command it reads in three steps:
+//! [`NativeAppGlue::pre_exec_cmd()`], the application's callback, and then
+//! [`NativeAppGlue::post_exec_cmd()`]. Every change of state made by these steps
+//! that the Java thread may be waiting for is followed by notifying the glue's
+//! condition variable.
+//!
+//! Some callbacks block the Java main thread until the application has
+//! acknowledged a command:
+//!
+//! - `onStart`, `onResume`, `onPause` and `onStop` wait until `pre_exec_cmd()` has
+//!   updated the activity state, so they don't wait for the application to
+//!   handle the event.
+//! - `onNativeWindowCreated` waits until `pre_exec_cmd()` has taken the pending
+//!   window, for `APP_CMD_INIT_WINDOW`.
+//! - `onNativeWindowDestroyed` waits until `post_exec_cmd()` has cleared the
+//!   window, for `APP_CMD_TERM_WINDOW`. This means the window remains valid for
+//!   the whole time that the application is handling `TerminateWindow`, and
+//!   since the application thread terminates all `NativeWindowHandle`s before
+//!   calling `post_exec_cmd()`, no handle can reach the window once
+//!   `onNativeWindowDestroyed` returns.
+//! - `onInputQueueCreated` and `onInputQueueDestroyed` wait until `pre_exec_cmd()`
+//!   has taken the pending input queue. The input queue is only read by the
+//!   application thread, so it can't be used after it has been replaced.
+//! - `onSaveInstanceState` waits until `post_exec_cmd()` has been called for
+//!   `APP_CMD_SAVE_STATE`, after the application has stored its state.
+//! - `onDestroy` waits until the application thread has exited.
+//!
+//! The remaining callbacks only write a command and return immediately.
+//!
+//! The application thread never waits for the Java main thread, and it only holds
+//! the mutex briefly, so it's not possible for the two threads to deadlock.
+//! Unlike the C glue layer, the Java main thread also stops waiting once the
+//! application thread has exited, so the activity won't hang if `android_main`
+//! returns (or panics) early.
+
+use log::error;
+use ndk_sys::{AInputQueue, ANativeWindow};
+use std::collections::VecDeque;
+use std::os::unix::prelude::*;
+use std::ptr::NonNull;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+
+use crate::poll::AppCmd;
+use crate::{Rect, Size};
+
+pub(crate) use crate::poll::{LOOPER_ID_INPUT, LOOPER_ID_MAIN};
+
+pub(crate) const APP_CMD_INPUT_CHANGED: i8 = AppCmd::InputChanged as i8;
+pub(crate) const APP_CMD_INIT_WINDOW: i8 = AppCmd::InitWindow as i8;
+pub(crate) const APP_CMD_TERM_WINDOW: i8 = AppCmd::TermWindow as i8;
+pub(crate) const APP_CMD_WINDOW_RESIZED: i8 = AppCmd::WindowResized as i8;
+pub(crate) const APP_CMD_WINDOW_REDRAW_NEEDED: i8 = AppCmd::WindowRedrawNeeded as i8;
+pub(crate) const APP_CMD_CONTENT_RECT_CHANGED: i8 = AppCmd::ContentRectChanged as i8;
+pub(crate) const APP_CMD_GAINED_FOCUS: i8 = AppCmd::GainedFocus as i8;
+pub(crate) const APP_CMD_LOST_FOCUS: i8 = AppCmd::LostFocus as i8;
+pub(crate) const APP_CMD_CONFIG_CHANGED: i8 = AppCmd::ConfigChanged as i8;
+pub(crate) const APP_CMD_LOW_MEMORY: i8 = AppCmd::LowMemory as i8;
+pub(crate) const APP_CMD_START: i8 = AppCmd::Start as i8;
+pub(crate) const APP_CMD_RESUME: i8 = AppCmd::Resume as i8;
+pub(crate) const APP_CMD_SAVE_STATE: i8 = AppCmd::SaveState as i8;
+pub(crate) const APP_CMD_PAUSE: i8 = AppCmd::Pause as i8;
+pub(crate) const APP_CMD_STOP: i8 = AppCmd::Stop as i8;
+pub(crate) const APP_CMD_DESTROY: i8 = AppCmd::Destroy as i8;
+
+// The equivalent of the `android_app` struct
+#[derive(Debug, Default)]
+pub(crate) struct GlueState {
+    /// The last of `APP_CMD_START`, `_RESUME`, `_PAUSE` or `_STOP` that the
+    /// application started handling
+    pub(crate) activity_state: i8,
+
+    /// The window that the application is currently using, which is set in
+    /// `pre_exec_cmd(APP_CMD_INIT_WINDOW)` and cleared in
+    /// `post_exec_cmd(APP_CMD_TERM_WINDOW)`
+    pub(crate) window: Option<NonNull<ANativeWindow>>,
+
+    /// The window most recently passed to `set_window()`
+    pub(crate) pending_window: Option<NonNull<ANativeWindow>>,
+
+    /// The input queue that the application is currently using, which is
+    /// updated in `pre_exec_cmd(APP_CMD_INPUT_CHANGED)`
+    pub(crate) input_queue: Option<NonNull<AInputQueue>>,
+    pending_input_queue: Option<NonNull<AInputQueue>>,
+
+    pub(crate) content_rect: Rect,
+
+    // Each content rect and window size change is queued alongside its command,
+    // so that the changes aren't coalesced before the application sees them
+    pending_content_rects: VecDeque<Rect>,
+
+    /// The size of the window, as of the last `APP_CMD_INIT_WINDOW` or
+    /// `APP_CMD_WINDOW_RESIZED` that the application started handling
+    pub(crate) window_size: Size,
+    pending_window_sizes: VecDeque<Size>,
+
+    /// The state that was passed when the activity was created, until the
+    /// application has handled `APP_CMD_RESUME`, or else the state that the
+    /// application stored while handling `APP_CMD_SAVE_STATE`
+    pub(crate) saved_state: Option<Vec<u8>>,
+
+    state_saved: bool,
+    running: bool,
+    destroyed: bool,
+}
+
+// The window and input queue pointers are only handed between threads while
+// holding the lock, following the protocol described above
+unsafe impl Send for GlueState {}
+
+#[derive(Debug)]
+pub(crate) struct NativeAppGlue {
+    state: Mutex<GlueState>,
+    cond: Condvar,
+    msgread: RawFd,
+    msgwrite: RawFd,
+}
+
+impl NativeAppGlue {
+    pub(crate) fn new(saved_state: Option<&[u8]>) -> Self {
+        let mut msgpipe: [RawFd; 2] = Default::default();
+        unsafe {
+            if libc::pipe2(msgpipe.as_mut_ptr(), libc::O_CLOEXEC) != 0 {
+                panic!("Could not create command pipe: {}", std::io::Error::last_os_error());
+            }
+        }
+        Self {
+            state: Mutex::new(GlueState {
+                saved_state: saved_state.map(|state| state.to_vec()),
+                ..Default::default()
+            }),
+            cond: Condvar::new(),
+            msgread: msgpipe[0],
+            msgwrite: msgpipe[1],
+        }
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, GlueState> {
+        self.state.lock().unwrap()
+    }
+
+    /// The read end of the command pipe, to be monitored by the application's looper
+    pub(crate) fn msgread(&self) -> RawFd {
+        self.msgread
+    }
+
+    // Blocks the Java main thread until `condition` is false or the
+    // application thread has exited, whichever comes first
+    fn wait_while<'a>(&self, guard: MutexGuard<'a, GlueState>, mut condition: impl FnMut(&mut GlueState) -> bool) -> MutexGuard<'a, GlueState> {
+        self.cond.wait_while(guard, |state| !state.destroyed && condition(state)).unwrap()
+    }
+
+    // Java main thread ---------------------------------------------------------
+
+    pub(crate) fn write_cmd(&self, cmd: i8) {
+        unsafe {
+            if libc::write(self.msgwrite, &cmd as *const i8 as *const libc::c_void, 1) != 1 {
+                error!("Failure writing android_app cmd: {}", std::io::Error::last_os_error());
+            }
+        }
+    }
+
+    pub(crate) fn wait_until_running(&self) {
+        let state = self.lock();
+        drop(self.wait_while(state, |state| !state.running));
+    }
+
+    pub(crate) fn set_activity_state(&self, cmd: i8) {
+        let state = self.lock();
+        self.write_cmd(cmd);
+        drop(self.wait_while(state, |state| state.activity_state != cmd));
+    }
+
+    pub(crate) fn save_instance_state(&self) -> Option<Vec<u8>> {
+        let mut state = self.lock();
+        state.state_saved = false;
+        self.write_cmd(APP_CMD_SAVE_STATE);
+        let mut state = self.wait_while(state, |state| !state.state_saved);
+        state.saved_state.take()
+    }
+
+    pub(crate) fn set_window(&self, window: Option<NonNull<ANativeWindow>>) {
+        let mut state = self.lock();
+        if state.pending_window.is_some() {
+            self.write_cmd(APP_CMD_TERM_WINDOW);
+        }
+        state.pending_window = window;
+        if window.is_some() {
+            self.write_cmd(APP_CMD_INIT_WINDOW);
+        }
+        drop(self.wait_while(state, |state| state.window != state.pending_window));
+    }
+
+    pub(crate) fn set_input_queue(&self, queue: Option<NonNull<AInputQueue>>) {
+        let mut state = self.lock();
+        state.pending_input_queue = queue;
+        self.write_cmd(APP_CMD_INPUT_CHANGED);
+        drop(self.wait_while(state, |state| state.input_queue != state.pending_input_queue));
+    }
+
+    pub(crate) fn set_content_rect(&self, rect: Rect) {
+        let mut state = self.lock();
+        state.pending_content_rects.push_back(rect);
+        self.write_cmd(APP_CMD_CONTENT_RECT_CHANGED);
+    }
+
+    pub(crate) fn set_window_size(&self, size: Size) {
+        let mut state = self.lock();
+        state.pending_window_sizes.push_back(size);
+        self.write_cmd(APP_CMD_WINDOW_RESIZED);
+    }
+
+    /// Asks the application to exit and waits until it has
+    pub(crate) fn destroy(&self) {
+        let state = self.lock();
+        if !state.destroyed {
+            self.write_cmd(APP_CMD_DESTROY);
+        }
+        drop(self.wait_while(state, |_| true));
+    }
+
+    #[allow(dead_code)] // Only used by the test backend
+    pub(crate) fn is_destroyed(&self) -> bool {
+        self.lock().destroyed
+    }
+
+    // Application thread -------------------------------------------------------
+
+    pub(crate) fn set_running(&self) {
+        let mut state = self.lock();
+        state.running = true;
+        self.cond.notify_all();
+    }
+
+    pub(crate) fn read_cmd(&self) -> i8 {
+        let mut cmd: i8 = 0;
+        unsafe {
+            if libc::read(self.msgread, &mut cmd as *mut i8 as *mut libc::c_void, 1) != 1 {
+                error!("No data on command pipe!");
+                return -1;
+            }
+        }
+        if cmd == APP_CMD_SAVE_STATE {
+            self.lock().saved_state = None;
+        }
+        cmd
+    }
+
+    pub(crate) fn pre_exec_cmd(&self, cmd: i8) {
+        let mut state = self.lock();
+        match cmd {
+            APP_CMD_INPUT_CHANGED => {
+                state.input_queue = state.pending_input_queue;
+                self.cond.notify_all();
+            }
+            APP_CMD_INIT_WINDOW => {
+                // The Java main thread can't resize the new window until it's
+                // been taken, so this is the size it was created with
+                state.window = state.pending_window;
+                state.window_size = state.window.map(|window| unsafe {
+                    Size {
+                        width: ndk_sys::ANativeWindow_getWidth(window.as_ptr()),
+                        height: ndk_sys::ANativeWindow_getHeight(window.as_ptr()),
+                    }
+                }).unwrap_or_default();
+                self.cond.notify_all();
+            }
+            APP_CMD_RESUME | APP_CMD_START | APP_CMD_PAUSE | APP_CMD_STOP => {
+                state.activity_state = cmd;
+                self.cond.notify_all();
+            }
+            APP_CMD_CONTENT_RECT_CHANGED => {
+                if let Some(rect) = state.pending_content_rects.pop_front() {
+                    state.content_rect = rect;
+                }
+            }
+            APP_CMD_WINDOW_RESIZED => {
+                if let Some(size) = state.pending_window_sizes.pop_front() {
+                    state.window_size = size;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn post_exec_cmd(&self, cmd: i8) {
+        let mut state = self.lock();
+        match cmd {
+            APP_CMD_TERM_WINDOW => {
+                state.window = None;
+                self.cond.notify_all();
+            }
+            APP_CMD_SAVE_STATE => {
+                state.state_saved = true;
+                self.cond.notify_all();
+            }
+            APP_CMD_RESUME => {
+                state.saved_state = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn set_destroyed(&self) {
+        let mut state = self.lock();
+        state.saved_state = None;
+        state.input_queue = None;
+        state.destroyed = true;
+        self.cond.notify_all();
+    }
+}
+
+impl Drop for NativeAppGlue {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.msgread);
+            libc::close(self.msgwrite);
+        }
+    }
+}
+
+/// Marks the application as destroyed once the application thread is finished
+/// with the glue, even if it unwinds
+pub(crate) struct DestroyGuard(pub(crate) Arc<NativeAppGlue>);
+
+impl Drop for DestroyGuard {
+    fn drop(&mut self) {
+        self.0.set_destroyed();
+    }
+}
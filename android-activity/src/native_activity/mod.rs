@@ -0,0 +1,506 @@
+use log::{error, trace};
+use ndk::asset::AssetManager;
+use ndk::configuration::Configuration;
+use ndk::input_queue::InputQueue;
+use ndk::native_activity::NativeActivity;
+use ndk_sys::{ALooper, ANativeActivity, ARect, AInputQueue, ANativeWindow};
+use std::cell::{Cell, RefCell};
+use std::os::raw;
+use std::os::unix::prelude::*;
+use std::path::PathBuf;
+use std::ptr::NonNull;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
+use std::ptr;
+
+use crate::{AndroidApp, AndroidAppWaker, Assets, Rect, Size, TrimMemoryLevel};
+use crate::fd_sources::FdSources;
+use crate::finish::Finisher;
+use crate::poll::{GlueBackend, PollState};
+use crate::state_spill::StateSpill;
+use crate::lifecycle::Lifecycle;
+use crate::recording::RecordingState;
+
+mod glue;
+use glue::*;
+
+pub mod input {
+    pub use ndk::event::{
+        InputEvent, Source, MetaState,
+        MotionEvent, Pointer, MotionAction, Axis, ButtonState, EdgeFlags, MotionEventFlags,
+        KeyEvent, KeyAction, Keycode, KeyEventFlags
+    };
+}
+
+use input::InputEvent;
+
+/// `NativeActivity` specific extensions to [`AndroidApp`]
+pub trait AndroidAppExt {
+    /// Returns the [`NativeActivity`] that this application is running within
+    fn native_activity(&self) -> NativeActivity;
+}
+
+impl AndroidAppExt for AndroidApp {
+    fn native_activity(&self) -> NativeActivity {
+        unsafe { NativeActivity::from_ptr(self.inner.activity) }
+    }
+}
+
+// The only time it's safe to update the saved state is while handling a
+// SaveState event, so this API is only exposed for those events...
+#[derive(Debug)]
+pub(crate) struct StateSaver<'a> {
+    pub(crate) app: &'a AndroidAppInner,
+}
+
+impl<'a> StateSaver<'a> {
+    pub fn store(&self, state: &[u8]) {
+        // The state is copied into a `malloc`'d buffer for the Java main thread
+        // in `on_save_instance_state`, once the event has been handled
+        self.app.glue.lock().saved_state = Some(state.to_vec());
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct StateLoader<'a> {
+    pub(crate) app: &'a AndroidAppInner,
+}
+impl<'a> StateLoader<'a> {
+    pub fn load(&self) -> Option<Vec<u8>> {
+        self.app.glue.lock().saved_state.clone().filter(|state| !state.is_empty())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct AndroidAppInner {
+    glue: Arc<NativeAppGlue>,
+    activity: NonNull<ANativeActivity>,
+    looper: NonNull<ALooper>,
+    poll: PollState,
+
+    // Whether the current input queue is attached to the looper, which is
+    // paused from when new input is reported until the next `input_events()`
+    input_attached: Cell<bool>,
+
+    lifecycle: Lifecycle,
+    recording: RefCell<RecordingState>,
+    state_spill: StateSpill,
+    finisher: Arc<Finisher>,
+}
+
+impl AndroidAppInner {
+
+    unsafe fn from_glue(glue: Arc<NativeAppGlue>, activity: NonNull<ANativeActivity>, looper: NonNull<ALooper>) -> AndroidApp {
+        // The looper is owned by the thread that prepared it, so we take our own
+        // reference to make sure it outlives any `AndroidApp` clones
+        ndk_sys::ALooper_acquire(looper.as_ptr());
+
+        // We keep our own deep copy of the configuration which is updated
+        // whenever we get a ConfigChanged notification
+        let asset_manager = AssetManager::from_ptr(NonNull::new_unchecked((*activity.as_ptr()).assetManager));
+        let config = Configuration::from_asset_manager(&asset_manager);
+
+        let state_spill = StateSpill::new(crate::path_from_ptr((*activity.as_ptr()).internalDataPath), glue.lock().saved_state.as_deref());
+        let app = AndroidAppInner {
+            glue,
+            activity,
+            looper,
+            poll: PollState::new(config, FdSources::new(looper)),
+            input_attached: Cell::new(false),
+            lifecycle: Lifecycle::default(),
+            recording: RefCell::new(RecordingState::default()),
+            state_spill,
+            finisher: Finisher::new(activity.cast(), finish_activity),
+        };
+
+        AndroidApp {
+            inner: Rc::new(app)
+        }
+    }
+
+    pub fn create_waker(&self) -> AndroidAppWaker {
+        AndroidAppWaker { looper: self.looper }
+    }
+
+    pub(crate) fn lifecycle(&self) -> &Lifecycle {
+        &self.lifecycle
+    }
+
+    pub(crate) fn recording(&self) -> &RefCell<RecordingState> {
+        &self.recording
+    }
+
+    pub(crate) fn state_spill(&self) -> &StateSpill {
+        &self.state_spill
+    }
+
+    pub fn content_rect(&self) -> Rect {
+        self.glue.lock().content_rect.clone()
+    }
+
+    pub fn asset_manager(&self) -> AssetManager {
+        unsafe {
+            let am_ptr = NonNull::new_unchecked((*self.activity.as_ptr()).assetManager);
+            AssetManager::from_ptr(am_ptr)
+        }
+    }
+
+    pub(crate) fn finisher(&self) -> &Arc<Finisher> {
+        &self.finisher
+    }
+
+    pub fn assets(&self) -> Assets {
+        Assets::from_asset_manager(self.asset_manager())
+    }
+
+    pub fn internal_data_path(&self) -> Option<PathBuf> {
+        unsafe { crate::path_from_ptr((*self.activity.as_ptr()).internalDataPath) }
+    }
+
+    pub fn external_data_path(&self) -> Option<PathBuf> {
+        unsafe { crate::path_from_ptr((*self.activity.as_ptr()).externalDataPath) }
+    }
+
+    pub fn obb_path(&self) -> Option<PathBuf> {
+        unsafe { crate::path_from_ptr((*self.activity.as_ptr()).obbPath) }
+    }
+
+    pub fn sdk_version(&self) -> u32 {
+        unsafe { (*self.activity.as_ptr()).sdkVersion as u32 }
+    }
+
+    pub fn input_events<F>(&self, mut callback: F) -> usize
+        where F: FnMut(&InputEvent)
+    {
+        // The input queue can only be replaced while this thread is handling
+        // an `APP_CMD_INPUT_CHANGED` command, so it stays valid until we return
+        let queue = match self.glue.lock().input_queue {
+            Some(queue) => queue,
+            None => return 0,
+        };
+        if !self.input_attached.get() {
+            self.attach_input_queue(queue);
+        }
+        let queue = unsafe { InputQueue::from_ptr(queue) };
+
+        trace!("collect_events: START");
+        while let Some(event) = queue.get_event() {
+            trace!("Got input event {event:?}");
+            if let Some(event) = queue.pre_dispatch(event) {
+                trace!("Pre dispatched input event {event:?}");
+
+                callback(&event);
+
+                // Always report events as 'handled'. This means we won't get
+                // so called 'fallback' events generated (such as converting trackball
+                // events into emulated keypad events), but we could conceivably
+                // implement similar emulation somewhere else in the stack if
+                // necessary, and this will be more consistent with the GameActivity
+                // input handling that doesn't do any kind of emulation.
+                trace!("Finishing input event {event:?}");
+                queue.finish_event(event, true);
+            }
+        }
+
+        0
+    }
+
+    fn attach_input_queue(&self, queue: NonNull<AInputQueue>) {
+        unsafe {
+            ndk_sys::AInputQueue_attachLooper(queue.as_ptr(), self.looper.as_ptr(), LOOPER_ID_INPUT, None, ptr::null_mut());
+        }
+        self.input_attached.set(true);
+    }
+
+    fn detach_input_queue(&self, queue: NonNull<AInputQueue>) {
+        if self.input_attached.replace(false) {
+            unsafe { ndk_sys::AInputQueue_detachLooper(queue.as_ptr()) };
+        }
+    }
+}
+
+impl GlueBackend for AndroidAppInner {
+    fn poll_state(&self) -> &PollState {
+        &self.poll
+    }
+
+    fn cmd_fd(&self) -> RawFd {
+        self.glue.msgread()
+    }
+
+    fn read_cmd(&self) -> Option<i8> {
+        let cmd_i = self.glue.read_cmd();
+        (cmd_i >= 0).then_some(cmd_i)
+    }
+
+    fn pre_exec_cmd(&self, cmd_i: i8) {
+        if cmd_i != APP_CMD_INPUT_CHANGED {
+            self.glue.pre_exec_cmd(cmd_i);
+            return;
+        }
+
+        // The old queue is only destroyed once the Java main thread sees that
+        // it's been replaced, so it must be detached first
+        let old_queue = self.glue.lock().input_queue;
+        if let Some(queue) = old_queue {
+            self.detach_input_queue(queue);
+        }
+        self.glue.pre_exec_cmd(cmd_i);
+        let new_queue = self.glue.lock().input_queue;
+        if let Some(queue) = new_queue {
+            self.attach_input_queue(queue);
+        }
+    }
+
+    fn post_exec_cmd(&self, cmd_i: i8) {
+        self.glue.post_exec_cmd(cmd_i);
+    }
+
+    fn new_window(&self) -> Option<(NonNull<ANativeWindow>, Size)> {
+        let state = self.glue.lock();
+        state.window.map(|window| (window, state.window_size))
+    }
+
+    fn resized_window_size(&self) -> Size {
+        self.glue.lock().window_size
+    }
+
+    fn changed_content_rect(&self) -> Rect {
+        self.glue.lock().content_rect.clone()
+    }
+
+    fn changed_config(&self) -> Configuration {
+        Configuration::from_asset_manager(&self.asset_manager())
+    }
+
+    // NativeActivity's onLowMemory is equivalent to onTrimMemory(TRIM_MEMORY_COMPLETE)
+    fn low_memory_level(&self) -> TrimMemoryLevel {
+        TrimMemoryLevel::Complete
+    }
+
+    fn input_available(&self) {
+        let queue = self.glue.lock().input_queue;
+        if let Some(queue) = queue {
+            self.detach_input_queue(queue);
+        }
+    }
+
+    fn state_loader(&self) -> crate::StateLoader<'_> {
+        crate::StateLoader::new(StateLoader { app: self })
+    }
+
+    fn state_saver(&self) -> crate::StateSaver<'_> {
+        crate::StateSaver::new(StateSaver { app: self })
+    }
+}
+
+impl Drop for AndroidAppInner {
+    fn drop(&mut self) {
+        let queue = self.glue.lock().input_queue;
+        if let Some(queue) = queue {
+            self.detach_input_queue(queue);
+        }
+        unsafe { ndk_sys::ALooper_release(self.looper.as_ptr()) };
+    }
+}
+
+// `ANativeActivity_finish()` only posts a request to the Java main thread, so
+// it can be called from any thread
+unsafe fn finish_activity(activity: NonNull<raw::c_void>) {
+    ndk_sys::ANativeActivity_finish(activity.cast().as_ptr());
+}
+
+// The `ANativeActivity` callbacks below are all called on the Java main thread,
+// see the `glue` module for how they synchronize with the application's thread
+
+unsafe fn glue_for_activity<'a>(activity: *mut ANativeActivity) -> &'a NativeAppGlue {
+    &*((*activity).instance as *const NativeAppGlue)
+}
+
+unsafe extern "C" fn on_destroy(activity: *mut ANativeActivity) {
+    trace!("Destroy: {activity:p}");
+    let _section = crate::trace::glue_section(c"onDestroy");
+    let glue = Arc::from_raw((*activity).instance as *const NativeAppGlue);
+    (*activity).instance = ptr::null_mut();
+    glue.destroy();
+}
+
+unsafe extern "C" fn on_start(activity: *mut ANativeActivity) {
+    trace!("Start: {activity:p}");
+    let _section = crate::trace::glue_section(c"onStart");
+    glue_for_activity(activity).set_activity_state(APP_CMD_START);
+}
+
+unsafe extern "C" fn on_resume(activity: *mut ANativeActivity) {
+    trace!("Resume: {activity:p}");
+    let _section = crate::trace::glue_section(c"onResume");
+    glue_for_activity(activity).set_activity_state(APP_CMD_RESUME);
+}
+
+unsafe extern "C" fn on_save_instance_state(activity: *mut ANativeActivity, out_len: *mut ndk_sys::size_t) -> *mut raw::c_void {
+    trace!("SaveInstanceState: {activity:p}");
+    let _section = crate::trace::glue_section(c"onSaveInstanceState");
+    let state = match glue_for_activity(activity).save_instance_state() {
+        Some(state) if !state.is_empty() => state,
+        _ => return ptr::null_mut(),
+    };
+
+    // The Java side takes ownership of the state and frees it with `free()`
+    let buf = libc::malloc(state.len());
+    if buf.is_null() {
+        error!("Failed to allocate saved state buffer");
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(state.as_ptr(), buf.cast(), state.len());
+    *out_len = state.len() as _;
+    buf
+}
+
+unsafe extern "C" fn on_pause(activity: *mut ANativeActivity) {
+    trace!("Pause: {activity:p}");
+    let _section = crate::trace::glue_section(c"onPause");
+    glue_for_activity(activity).set_activity_state(APP_CMD_PAUSE);
+}
+
+unsafe extern "C" fn on_stop(activity: *mut ANativeActivity) {
+    trace!("Stop: {activity:p}");
+    let _section = crate::trace::glue_section(c"onStop");
+    glue_for_activity(activity).set_activity_state(APP_CMD_STOP);
+}
+
+unsafe extern "C" fn on_configuration_changed(activity: *mut ANativeActivity) {
+    trace!("ConfigurationChanged: {activity:p}");
+    let _section = crate::trace::glue_section(c"onConfigurationChanged");
+    glue_for_activity(activity).write_cmd(APP_CMD_CONFIG_CHANGED);
+}
+
+unsafe extern "C" fn on_low_memory(activity: *mut ANativeActivity) {
+    trace!("LowMemory: {activity:p}");
+    let _section = crate::trace::glue_section(c"onLowMemory");
+    glue_for_activity(activity).write_cmd(APP_CMD_LOW_MEMORY);
+}
+
+unsafe extern "C" fn on_window_focus_changed(activity: *mut ANativeActivity, focused: raw::c_int) {
+    trace!("WindowFocusChanged: {activity:p} -- {focused}");
+    let _section = crate::trace::glue_section(c"onWindowFocusChanged");
+    glue_for_activity(activity).write_cmd(if focused != 0 { APP_CMD_GAINED_FOCUS } else { APP_CMD_LOST_FOCUS });
+}
+
+unsafe extern "C" fn on_native_window_created(activity: *mut ANativeActivity, window: *mut ANativeWindow) {
+    trace!("NativeWindowCreated: {activity:p} -- {window:p}");
+    let _section = crate::trace::glue_section(c"onNativeWindowCreated");
+    glue_for_activity(activity).set_window(NonNull::new(window));
+}
+
+unsafe extern "C" fn on_native_window_resized(activity: *mut ANativeActivity, window: *mut ANativeWindow) {
+    trace!("NativeWindowResized: {activity:p} -- {window:p}");
+    let _section = crate::trace::glue_section(c"onNativeWindowResized");
+    glue_for_activity(activity).set_window_size(Size {
+        width: ndk_sys::ANativeWindow_getWidth(window),
+        height: ndk_sys::ANativeWindow_getHeight(window),
+    });
+}
+
+unsafe extern "C" fn on_native_window_redraw_needed(activity: *mut ANativeActivity, window: *mut ANativeWindow) {
+    trace!("NativeWindowRedrawNeeded: {activity:p} -- {window:p}");
+    let _section = crate::trace::glue_section(c"onNativeWindowRedrawNeeded");
+    glue_for_activity(activity).write_cmd(APP_CMD_WINDOW_REDRAW_NEEDED);
+}
+
+unsafe extern "C" fn on_native_window_destroyed(activity: *mut ANativeActivity, window: *mut ANativeWindow) {
+    trace!("NativeWindowDestroyed: {activity:p} -- {window:p}");
+    let _section = crate::trace::glue_section(c"onNativeWindowDestroyed");
+    glue_for_activity(activity).set_window(None);
+}
+
+unsafe extern "C" fn on_input_queue_created(activity: *mut ANativeActivity, queue: *mut AInputQueue) {
+    trace!("InputQueueCreated: {activity:p} -- {queue:p}");
+    let _section = crate::trace::glue_section(c"onInputQueueCreated");
+    glue_for_activity(activity).set_input_queue(NonNull::new(queue));
+}
+
+unsafe extern "C" fn on_input_queue_destroyed(activity: *mut ANativeActivity, queue: *mut AInputQueue) {
+    trace!("InputQueueDestroyed: {activity:p} -- {queue:p}");
+    let _section = crate::trace::glue_section(c"onInputQueueDestroyed");
+    glue_for_activity(activity).set_input_queue(None);
+}
+
+unsafe extern "C" fn on_content_rect_changed(activity: *mut ANativeActivity, rect: *const ARect) {
+    trace!("ContentRectChanged: {activity:p} -- {rect:p}");
+    let _section = crate::trace::glue_section(c"onContentRectChanged");
+    let rect = &*rect;
+    glue_for_activity(activity).set_content_rect(Rect {
+        left: rect.left,
+        top: rect.top,
+        right: rect.right,
+        bottom: rect.bottom,
+    });
+}
+
+// Pointers that are only sent to the application thread once, when it's spawned
+struct SendActivity(NonNull<ANativeActivity>);
+unsafe impl Send for SendActivity {}
+
+// The equivalent of `android_app_entry`, run on the application's main thread
+unsafe fn app_entry(glue: Arc<NativeAppGlue>, activity: NonNull<ANativeActivity>) {
+    let _guard = DestroyGuard(glue.clone());
+
+    // The input queue is attached to the looper as `LOOPER_ID_INPUT` once it's
+    // delivered via `APP_CMD_INPUT_CHANGED`
+    let looper = ndk_sys::ALooper_prepare(ndk_sys::ALOOPER_PREPARE_ALLOW_NON_CALLBACKS as _);
+    ndk_sys::ALooper_addFd(looper, glue.msgread(), LOOPER_ID_MAIN, ndk_sys::ALOOPER_EVENT_INPUT as _, None, ptr::null_mut());
+    let app = AndroidAppInner::from_glue(glue.clone(), activity, NonNull::new(looper).unwrap());
+
+    glue.set_running();
+
+    let (vm, clazz) = ((*activity.as_ptr()).vm, (*activity.as_ptr()).clazz);
+    crate::run_android_main(app, vm.cast(), clazz.cast());
+}
+
+#[no_mangle]
+unsafe extern "C" fn ANativeActivity_onCreate(
+    activity: *mut ANativeActivity,
+    saved_state: *mut raw::c_void,
+    saved_state_size: usize,
+) {
+    trace!("Creating: {activity:p}");
+    let callbacks = &mut *(*activity).callbacks;
+    callbacks.onDestroy = Some(on_destroy);
+    callbacks.onStart = Some(on_start);
+    callbacks.onResume = Some(on_resume);
+    callbacks.onSaveInstanceState = Some(on_save_instance_state);
+    callbacks.onPause = Some(on_pause);
+    callbacks.onStop = Some(on_stop);
+    callbacks.onConfigurationChanged = Some(on_configuration_changed);
+    callbacks.onLowMemory = Some(on_low_memory);
+    callbacks.onWindowFocusChanged = Some(on_window_focus_changed);
+    callbacks.onNativeWindowCreated = Some(on_native_window_created);
+    callbacks.onNativeWindowResized = Some(on_native_window_resized);
+    callbacks.onNativeWindowRedrawNeeded = Some(on_native_window_redraw_needed);
+    callbacks.onNativeWindowDestroyed = Some(on_native_window_destroyed);
+    callbacks.onInputQueueCreated = Some(on_input_queue_created);
+    callbacks.onInputQueueDestroyed = Some(on_input_queue_destroyed);
+    callbacks.onContentRectChanged = Some(on_content_rect_changed);
+
+    let saved_state = if !saved_state.is_null() && saved_state_size > 0 {
+        Some(std::slice::from_raw_parts(saved_state as *const u8, saved_state_size))
+    } else {
+        None
+    };
+    let glue = Arc::new(NativeAppGlue::new(saved_state));
+    (*activity).instance = Arc::into_raw(glue.clone()) as *mut raw::c_void;
+
+    let activity = SendActivity(NonNull::new(activity).unwrap());
+    {
+        let glue = glue.clone();
+        thread::spawn(move || {
+            // Move the whole wrapper into the closure, not just the (non-Send) pointer
+            let activity = activity;
+            app_entry(glue, activity.0)
+        });
+    }
+
+    // Wait for the thread to start
+    glue.wait_until_running();
+}
@@ -0,0 +1,77 @@
+use ndk::native_window::NativeWindow;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// The error returned when accessing a [`NativeWindowHandle`] after the application
+/// has finished handling [`MainEvent::TerminateWindow`](crate::MainEvent::TerminateWindow)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowTerminatedError;
+
+impl fmt::Display for WindowTerminatedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The native window has been terminated")
+    }
+}
+
+impl std::error::Error for WindowTerminatedError {}
+
+/// A reference-counted handle to the application's [`NativeWindow`]
+///
+/// A handle can be cloned and sent to other threads (such as a render thread) but
+/// the underlying window is only valid between [`MainEvent::InitWindow`] and
+/// [`MainEvent::TerminateWindow`]. Once the application returns from its
+/// `TerminateWindow` callback then all handles to the window are invalidated and
+/// any further access will return a [`WindowTerminatedError`], instead of
+/// touching a window that Android may have already destroyed.
+///
+/// [`MainEvent::InitWindow`]: crate::MainEvent::InitWindow
+/// [`MainEvent::TerminateWindow`]: crate::MainEvent::TerminateWindow
+#[derive(Debug, Clone)]
+pub struct NativeWindowHandle {
+    window: Arc<RwLock<Option<NativeWindow>>>,
+}
+
+impl NativeWindowHandle {
+    pub(crate) fn new(window: NativeWindow) -> Self {
+        Self { window: Arc::new(RwLock::new(Some(window))) }
+    }
+
+    // Called after the application has handled `TerminateWindow`, before
+    // acknowledging the termination to the Java main thread. This blocks
+    // until any other thread that's inside `with_window()` is done with
+    // the window.
+    pub(crate) fn terminate(&self) {
+        *self.window.write().unwrap() = None;
+    }
+
+    /// Calls `f` with the [`NativeWindow`], unless the window has been terminated
+    ///
+    /// Termination of the window is blocked while `f` runs, so other threads can
+    /// safely use the window for the duration of the call.
+    ///
+    /// Calling [`AndroidApp::poll_events()`](crate::AndroidApp::poll_events) from
+    /// within `f` may deadlock.
+    pub fn with_window<F, R>(&self, f: F) -> Result<R, WindowTerminatedError>
+        where F: FnOnce(&NativeWindow) -> R
+    {
+        match &*self.window.read().unwrap() {
+            Some(window) => Ok(f(window)),
+            None => Err(WindowTerminatedError),
+        }
+    }
+
+    /// Returns `true` once the window has been terminated
+    pub fn is_terminated(&self) -> bool {
+        self.window.read().unwrap().is_none()
+    }
+
+    /// Queries the current width of the window in pixels
+    pub fn width(&self) -> Result<i32, WindowTerminatedError> {
+        self.with_window(|window| window.width())
+    }
+
+    /// Queries the current height of the window in pixels
+    pub fn height(&self) -> Result<i32, WindowTerminatedError> {
+        self.with_window(|window| window.height())
+    }
+}
@@ -0,0 +1,378 @@
+//! The parts of [`AndroidApp::poll_events()`](crate::AndroidApp::poll_events)
+//! that are common to every backend
+//!
+//! Each backend's glue layer delivers the same commands, via a pipe that's
+//! registered with the application's looper as [`LOOPER_ID_MAIN`], and handles
+//! each of them in the same three steps as `android_native_app_glue`:
+//! `pre_exec_cmd()`, the application's callback, and then `post_exec_cmd()`.
+//! The backends only differ in how those steps are implemented and where the
+//! state that accompanies a command (such as a new window size) is read from,
+//! which is abstracted by [`GlueBackend`].
+
+use log::{error, trace, warn};
+use ndk::configuration::Configuration;
+use ndk::looper::FdEvent;
+use ndk::native_window::NativeWindow;
+use ndk_sys::{ALooper_pollAll, ANativeWindow};
+use std::cell::Cell;
+use std::os::unix::prelude::*;
+use std::ptr::{self, NonNull};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::fd_sources::FdSources;
+use crate::{ConfigurationDiff, MainEvent, NativeWindowHandle, PollEvent, Rect, Size, TrimMemoryLevel, WindowInsets};
+
+/// The looper id of the glue layer's command pipe
+pub(crate) const LOOPER_ID_MAIN: i32 = 1;
+
+/// The looper id of the input queue, for backends that attach one
+pub(crate) const LOOPER_ID_INPUT: i32 = 2;
+
+/// The commands that the glue layer sends to the application's thread, which
+/// every backend numbers the same as `android_native_app_glue`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i8)]
+pub(crate) enum AppCmd {
+    InputChanged = 0,
+    InitWindow = 1,
+    TermWindow = 2,
+    WindowResized = 3,
+    WindowRedrawNeeded = 4,
+    ContentRectChanged = 5,
+    GainedFocus = 6,
+    LostFocus = 7,
+    ConfigChanged = 8,
+    LowMemory = 9,
+    Start = 10,
+    Resume = 11,
+    SaveState = 12,
+    Pause = 13,
+    Stop = 14,
+    Destroy = 15,
+    WindowInsetsChanged = 16,
+}
+
+impl AppCmd {
+    fn from_raw(cmd_i: i8) -> Option<Self> {
+        Some(match cmd_i {
+            0 => AppCmd::InputChanged,
+            1 => AppCmd::InitWindow,
+            2 => AppCmd::TermWindow,
+            3 => AppCmd::WindowResized,
+            4 => AppCmd::WindowRedrawNeeded,
+            5 => AppCmd::ContentRectChanged,
+            6 => AppCmd::GainedFocus,
+            7 => AppCmd::LostFocus,
+            8 => AppCmd::ConfigChanged,
+            9 => AppCmd::LowMemory,
+            10 => AppCmd::Start,
+            11 => AppCmd::Resume,
+            12 => AppCmd::SaveState,
+            13 => AppCmd::Pause,
+            14 => AppCmd::Stop,
+            15 => AppCmd::Destroy,
+            16 => AppCmd::WindowInsetsChanged,
+            _ => return None,
+        })
+    }
+}
+
+/// The state that `poll_events()` keeps up to date as it handles commands
+#[derive(Debug)]
+pub(crate) struct PollState {
+    config: RwLock<Configuration>,
+    native_window: RwLock<Option<NativeWindowHandle>>,
+
+    // The command currently being handled by a `poll_events()` callback
+    in_flight_cmd: Cell<Option<i8>>,
+
+    // The window size that was last reported to the application, as the
+    // `old_size` for the next `WindowResized` event
+    window_size: Cell<Size>,
+
+    fd_sources: FdSources,
+}
+
+impl PollState {
+    pub(crate) fn new(config: Configuration, fd_sources: FdSources) -> Self {
+        Self {
+            config: RwLock::new(config),
+            native_window: RwLock::new(None),
+            in_flight_cmd: Cell::new(None),
+            window_size: Cell::new(Size::default()),
+            fd_sources,
+        }
+    }
+
+    /// Returns a deep copy of the configuration, as of the last `ConfigChanged`
+    pub(crate) fn config(&self) -> Configuration {
+        self.config.read().unwrap().clone()
+    }
+
+    pub(crate) fn native_window(&self) -> Option<NativeWindowHandle> {
+        self.native_window.read().unwrap().clone()
+    }
+
+    pub(crate) fn window_size(&self) -> Size {
+        self.window_size.get()
+    }
+
+    /// Records the size of a window that's about to be delivered via
+    /// `InitWindow`, for backends that must query it before `pre_exec_cmd()`
+    #[cfg(feature = "game-activity")]
+    pub(crate) fn set_window_size(&self, size: Size) {
+        self.window_size.set(size);
+    }
+
+    pub(crate) fn fd_sources(&self) -> &FdSources {
+        &self.fd_sources
+    }
+}
+
+/// A backend's implementation of the glue layer's command handling
+///
+/// All the methods must only be called from the application's main thread.
+pub(crate) trait GlueBackend {
+    fn poll_state(&self) -> &PollState;
+
+    /// The read end of the glue layer's command pipe, which is registered with
+    /// the looper as [`LOOPER_ID_MAIN`]
+    fn cmd_fd(&self) -> RawFd;
+
+    /// Whether `fd` is one of the glue layer's own file descriptors, which
+    /// mustn't be registered by the application
+    fn is_glue_fd(&self, fd: RawFd) -> bool {
+        fd == self.cmd_fd()
+    }
+
+    /// Reads the next command from the glue layer's pipe, or returns `None` if
+    /// nothing could be read (after logging the error)
+    fn read_cmd(&self) -> Option<i8>;
+
+    fn pre_exec_cmd(&self, cmd_i: i8);
+
+    fn post_exec_cmd(&self, cmd_i: i8);
+
+    /// The window that `pre_exec_cmd()` took for `InitWindow`, and its size
+    fn new_window(&self) -> Option<(NonNull<ANativeWindow>, Size)>;
+
+    /// The new size of the window for `WindowResized`
+    fn resized_window_size(&self) -> Size;
+
+    /// The new content rect for `ContentRectChanged`
+    fn changed_content_rect(&self) -> Rect;
+
+    /// The new configuration for `ConfigChanged`
+    fn changed_config(&self) -> Configuration;
+
+    fn low_memory_level(&self) -> TrimMemoryLevel;
+
+    /// Called when the looper reports [`LOOPER_ID_INPUT`], before delivering a
+    /// `Wake`
+    ///
+    /// The input source stays readable until its events are read, so it must
+    /// be detached from the looper until the next call to `input_events()` to
+    /// avoid waking the application repeatedly.
+    fn input_available(&self) {}
+
+    /// The new insets for `WindowInsetsChanged`, which is only sent by
+    /// backends that track insets
+    fn changed_insets(&self) -> WindowInsets {
+        WindowInsets::default()
+    }
+
+    fn state_loader(&self) -> crate::StateLoader<'_>;
+
+    fn state_saver(&self) -> crate::StateSaver<'_>;
+
+    /// Polls the looper once, handling any command from the glue layer and
+    /// passing the resulting event to `callback`
+    fn poll_events<F>(&self, timeout: Option<Duration>, mut callback: F)
+        where F: FnMut(PollEvent), Self: Sized
+    {
+        trace!("poll_events");
+
+        let mut callback = |event: PollEvent| {
+            let _section = crate::trace::glue_section(c"poll_events callback");
+            callback(event)
+        };
+
+        let mut fd: i32 = 0;
+        let mut events: i32 = 0;
+        let mut source: *mut core::ffi::c_void = ptr::null_mut();
+
+        let timeout_milliseconds = if let Some(timeout) = timeout { timeout.as_millis() as i32 } else { -1 };
+        trace!("Calling ALooper_pollAll, timeout = {timeout_milliseconds}");
+        let id = unsafe {
+            let _section = crate::trace::glue_section(c"ALooper_pollAll");
+            ALooper_pollAll(timeout_milliseconds, &mut fd, &mut events, &mut source as *mut *mut core::ffi::c_void)
+        };
+        match id {
+            ndk_sys::ALOOPER_POLL_WAKE => {
+                trace!("ALooper_pollAll returned POLL_WAKE");
+                callback(PollEvent::Wake);
+            }
+            ndk_sys::ALOOPER_POLL_CALLBACK => {
+                // ALooper_pollAll is documented to handle all callback sources internally so it should
+                // never return a _CALLBACK source id...
+                error!("Spurious ALOOPER_POLL_CALLBACK from ALopper_pollAll() (ignored)");
+            }
+            ndk_sys::ALOOPER_POLL_TIMEOUT => {
+                trace!("ALooper_pollAll returned POLL_TIMEOUT");
+                callback(PollEvent::Timeout);
+            }
+            ndk_sys::ALOOPER_POLL_ERROR => {
+                trace!("ALooper_pollAll returned POLL_ERROR");
+                callback(PollEvent::Error);
+
+                // Considering that this API is quite likely to be used in `android_main`
+                // it's rather unergonomic to require the call to unwrap a Result for each
+                // call to poll_events(). Alternatively we could maybe even just panic!()
+                // here, while it's hard to imagine practically being able to recover
+                //return Err(LooperError);
+            }
+            LOOPER_ID_MAIN => {
+                trace!("ALooper_pollAll returned ID_MAIN");
+                self.handle_cmd(&mut callback);
+            }
+            LOOPER_ID_INPUT => {
+                trace!("ALooper_pollAll returned ID_INPUT");
+                self.input_available();
+                callback(PollEvent::Wake);
+            }
+            id if id >= 0 => {
+                // Newer versions of Android may report flags that we don't know about
+                let events = FdEvent::from_bits_truncate(events as u32);
+                trace!("Custom ALooper event source: id = {id}, fd = {fd}, events = {events:?}, data = {source:?}");
+                match self.poll_state().fd_sources.event(id, events) {
+                    Some(event) => callback(event),
+                    None => trace!("Ignoring event for unregistered ALooper source {id}"),
+                }
+            }
+            _ => {
+                error!("Spurious ALooper_pollAll return value {id} (ignored)");
+            }
+        }
+    }
+
+    /// Reads and handles a single command from the glue layer
+    fn handle_cmd<F>(&self, callback: &mut F)
+        where F: FnMut(PollEvent), Self: Sized
+    {
+        let cmd_i = {
+            let _section = crate::trace::glue_section(c"android_app_read_cmd");
+            self.read_cmd()
+        };
+        let Some(cmd_i) = cmd_i else { return };
+        let Some(cmd) = AppCmd::from_raw(cmd_i) else {
+            warn!("Ignoring unknown glue command {cmd_i}");
+            return;
+        };
+
+        trace!("Calling pre_exec_cmd({cmd_i})");
+        {
+            let _section = crate::trace::glue_section(c"android_app_pre_exec_cmd");
+            self.pre_exec_cmd(cmd_i);
+        }
+        let state = self.poll_state();
+        state.in_flight_cmd.set(Some(cmd_i));
+
+        let event = match cmd {
+            // We don't forward info about the AInputQueue to apps since it's
+            // an implementation details that's also not compatible with
+            // GameActivity
+            AppCmd::InputChanged => None,
+
+            AppCmd::InitWindow => {
+                if let Some((window, size)) = self.new_window() {
+                    state.window_size.set(size);
+                    *state.native_window.write().unwrap() =
+                        Some(NativeWindowHandle::new(unsafe { NativeWindow::from_ptr(window) }));
+                }
+                Some(MainEvent::InitWindow {})
+            }
+            AppCmd::TermWindow => Some(MainEvent::TerminateWindow {}),
+            AppCmd::WindowResized => {
+                let new_size = self.resized_window_size();
+                Some(MainEvent::WindowResized { old_size: state.window_size.replace(new_size), new_size })
+            }
+            AppCmd::WindowRedrawNeeded => Some(MainEvent::RedrawNeeded {}),
+            AppCmd::ContentRectChanged => Some(MainEvent::ContentRectChanged {
+                rect: self.changed_content_rect()
+            }),
+            AppCmd::GainedFocus => Some(MainEvent::GainedFocus),
+            AppCmd::LostFocus => Some(MainEvent::LostFocus),
+            AppCmd::ConfigChanged => {
+                let old = state.config();
+                let new = self.changed_config();
+                *state.config.write().unwrap() = new.clone();
+                Some(MainEvent::ConfigChanged { diff: ConfigurationDiff::between(&old, &new), old, new })
+            }
+            AppCmd::LowMemory => Some(MainEvent::LowMemory { level: self.low_memory_level() }),
+            AppCmd::Start => Some(MainEvent::Start),
+            AppCmd::Resume => Some(MainEvent::Resume { loader: self.state_loader() }),
+            AppCmd::SaveState => Some(MainEvent::SaveState { saver: self.state_saver() }),
+            AppCmd::Pause => Some(MainEvent::Pause),
+            AppCmd::Stop => Some(MainEvent::Stop),
+            AppCmd::Destroy => Some(MainEvent::Destroy),
+            AppCmd::WindowInsetsChanged => Some(MainEvent::InsetsChanged { insets: self.changed_insets() }),
+        };
+
+        if let Some(event) = event {
+            trace!("Invoking callback for ID_MAIN command {cmd_i} = {event:?}");
+            callback(PollEvent::Main(event));
+        }
+
+        // Unless the command was deferred (see `take_cmd_completion()`)
+        if let Some(cmd_i) = state.in_flight_cmd.take() {
+            self.complete_cmd(cmd_i);
+        }
+    }
+
+    /// Lets the application take over responsibility for completing the command
+    /// that's currently being handled, so the post-exec synchronization with the
+    /// Java main thread can be delayed until after the callback returns
+    ///
+    /// Returns `None` if no command is being handled (such as for a replayed
+    /// event). Otherwise the command must be passed to
+    /// `complete_deferred_cmd()`.
+    #[cfg(feature = "async")]
+    fn take_cmd_completion(&self) -> Option<i8> {
+        let cmd_i = self.poll_state().in_flight_cmd.take()?;
+
+        // Later commands mustn't be handled before this one is complete, since
+        // for example a window may be replaced by writing TermWindow and
+        // InitWindow together, so the command pipe is removed from the looper
+        // while the other sources continue to be polled
+        let looper = self.poll_state().fd_sources.looper();
+        unsafe { ndk_sys::ALooper_removeFd(looper.as_ptr(), self.cmd_fd()) };
+        Some(cmd_i)
+    }
+
+    /// Completes a command that was taken via `take_cmd_completion()` and
+    /// resumes handling commands
+    #[cfg(feature = "async")]
+    fn complete_deferred_cmd(&self, cmd_i: i8) {
+        self.complete_cmd(cmd_i);
+
+        let looper = self.poll_state().fd_sources.looper();
+        unsafe {
+            ndk_sys::ALooper_addFd(looper.as_ptr(), self.cmd_fd(), LOOPER_ID_MAIN, ndk_sys::ALOOPER_EVENT_INPUT as _, None, ptr::null_mut());
+        }
+    }
+
+    fn complete_cmd(&self, cmd_i: i8) {
+        // The window remains valid until the application has finished handling
+        // TerminateWindow
+        if cmd_i == AppCmd::TermWindow as i8 {
+            if let Some(window) = self.poll_state().native_window.write().unwrap().take() {
+                window.terminate();
+            }
+        }
+
+        trace!("Calling post_exec_cmd({cmd_i})");
+        let _section = crate::trace::glue_section(c"android_app_post_exec_cmd");
+        self.post_exec_cmd(cmd_i);
+    }
+}
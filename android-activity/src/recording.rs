@@ -0,0 +1,739 @@
+//! Recording and replaying of application events
+//!
+//! A recording captures every event that's delivered to the application via
+//! [`AndroidApp::poll_events()`] and [`AndroidApp::input_events()`], along with
+//! the time it was delivered, so that a sequence of events that leads to a bug
+//! (such as a rotation during a save followed by the loss of the window) can be
+//! reproduced later.
+//!
+//! Recording is opt-in, via [`AndroidApp::start_recording()`], and events are
+//! written to a compact binary file, such as in the application's internal data
+//! directory. Each event is written out as it's recorded, so the recording is
+//! still usable if the application crashes or is killed. A [`Recording`] can
+//! then be loaded and passed to [`AndroidApp::start_replay()`], either on a
+//! device or with the host-only `test` backend, after which the application will
+//! see the recorded events instead of real ones until the end of the recording.
+//!
+//! While replaying, the Java main thread's commands continue to be handled by the
+//! glue layer so that the Activity isn't blocked, but most of them aren't passed
+//! to the application. Real [`PollEvent::Fd`], [`PollEvent::Timer`],
+//! [`PollEvent::User`] and [`PollEvent::Frame`] events are still delivered, since
+//! the application asked for them, as are real [`MainEvent::SaveState`] events,
+//! so that the application's real state is still saved. A real
+//! [`MainEvent::TerminateWindow`] or [`MainEvent::Destroy`] ends the replay and is
+//! delivered as usual, since the application must not keep using a window or
+//! Activity that has gone. Replayed events are delivered with the same timing as when they
+//! were recorded, but some details can't be reproduced:
+//!
+//! - Window events don't create or destroy windows, so
+//!   [`AndroidApp::native_window()`] still refers to the real window, if any.
+//! - State stored while handling a replayed [`MainEvent::SaveState`] is discarded.
+//! - [`MainEvent::ConfigChanged`] events are recorded with their `diff`, but both the
+//!   `old` and `new` configurations are replayed as the current configuration, so
+//!   the `diff` is the only record of which fields changed.
+//! - Replayed lifecycle and focus events don't change
+//!   [`AndroidApp::lifecycle_state()`] or [`AndroidApp::is_focused()`], which
+//!   always follow the real Activity.
+//! - [`PollEvent::Fd`] and [`PollEvent::Timer`] events are recorded, but not
+//!   replayed, since the file descriptors and timers would no longer be registered.
+//! - [`PollEvent::User`] events are recorded without their value, and aren't
+//!   replayed.
+//! - [`PollEvent::Frame`] events are recorded, but not replayed, since they
+//!   depend on which frames the application requested.
+//! - Input events can only be recorded and replayed with the `GameActivity` (and
+//!   `test`) backend, since `NativeActivity` input events are owned by an
+//!   `AInputQueue`. With the `NativeActivity` backend
+//!   [`AndroidApp::start_recording()`] logs a warning that the recording won't
+//!   include any input.
+
+use log::error;
+use ndk::looper::FdEvent;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::{AndroidApp, ConfigurationDiff, FdToken, InsetType, MainEvent, PollEvent, Rect, Size, TimerId, TrimMemoryLevel, WindowInsets};
+use crate::poll::GlueBackend;
+
+#[cfg(any(feature = "game-activity", feature = "test"))]
+use crate::activity_impl::ffi::{GameActivityKeyEvent, GameActivityMotionEvent, GameActivityPointerAxes};
+#[cfg(any(feature = "game-activity", feature = "test"))]
+use crate::input::{InputEvent, KeyEvent, MotionEvent};
+
+const MAGIC: &[u8; 6] = b"AAREC\0";
+const VERSION: u8 = 3;
+
+/// An owned copy of an event that was delivered to the application
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub enum RecordedEvent {
+    Wake,
+    Timeout,
+    Error,
+    /// [`PollEvent::Fd`], with the token of the registered fd
+    Fd { token: FdToken, events: FdEvent },
+    Timer(TimerId),
+    /// [`PollEvent::User`], without the event's value
+    User,
+    Frame { frame_time_nanos: i64, vsync_id: Option<i64> },
+    /// [`MainEvent::InitWindow`], with the size of the new window
+    InitWindow { width: i32, height: i32 },
+    TerminateWindow,
+    WindowResized { old_size: Size, new_size: Size },
+    RedrawNeeded,
+    ContentRectChanged(Rect),
+    GainedFocus,
+    LostFocus,
+    /// [`MainEvent::ConfigChanged`], with the fields that changed
+    ConfigChanged(ConfigurationDiff),
+    LowMemory(TrimMemoryLevel),
+    Start,
+    /// [`MainEvent::Resume`], with the state that was available to load
+    Resume { saved_state: Option<Vec<u8>> },
+    SaveState,
+    Pause,
+    Stop,
+    Destroy,
+    InsetsChanged(WindowInsets),
+    /// An event that was returned by [`AndroidApp::input_events()`]
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    Input(Box<InputEvent>),
+}
+
+/// A [`RecordedEvent`] along with the time it was delivered, relative to the
+/// start of the recording
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub time: Duration,
+    pub event: RecordedEvent,
+}
+
+/// A sequence of events that was recorded via [`AndroidApp::start_recording()`]
+#[derive(Debug, Clone, Default)]
+pub struct Recording {
+    records: Vec<Record>,
+}
+
+impl Recording {
+    /// Loads a recording from a file that was written by [`AndroidApp::start_recording()`]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a recording that was written by [`AndroidApp::start_recording()`]
+    ///
+    /// If the recording ends with an incomplete record, such as when the
+    /// application was killed while writing it, the complete records before it
+    /// are returned.
+    pub fn read_from<R: Read>(reader: R) -> io::Result<Self> {
+        let mut decoder = Decoder { reader };
+        let mut magic = [0u8; 6];
+        decoder.reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Not an event recording"));
+        }
+        let version = decoder.u8()?;
+        if version != VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unsupported event recording version {version}")));
+        }
+
+        let mut records = vec![];
+        let mut time = Duration::ZERO;
+        loop {
+            let record = decoder.varint().and_then(|delta| Ok((delta, decoder.event()?)));
+            let (delta, event) = match record {
+                Ok(record) => record,
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            };
+            time += Duration::from_nanos(delta);
+            records.push(Record { time, event });
+        }
+        Ok(Self { records })
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+}
+
+impl From<Vec<Record>> for Recording {
+    fn from(records: Vec<Record>) -> Self {
+        Self { records }
+    }
+}
+
+pub(crate) struct Recorder {
+    encoder: Encoder<BufWriter<File>>,
+    start: Instant,
+    last_time: Duration,
+}
+
+impl Recorder {
+    fn create(path: &Path) -> io::Result<Self> {
+        let mut encoder = Encoder { writer: BufWriter::new(File::create(path)?) };
+        encoder.writer.write_all(MAGIC)?;
+        encoder.u8(VERSION)?;
+        Ok(Self { encoder, start: Instant::now(), last_time: Duration::ZERO })
+    }
+
+    fn record(&mut self, event: &RecordedEvent) -> io::Result<()> {
+        let time = self.start.elapsed();
+        self.encoder.varint((time - self.last_time).as_nanos() as u64)?;
+        self.last_time = time;
+        self.encoder.event(event)?;
+
+        // Each record is written out as soon as it's complete, so that the
+        // recording leading up to a crash isn't lost
+        self.encoder.writer.flush()
+    }
+}
+
+impl std::fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recorder").field("start", &self.start).finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+struct Replay {
+    records: VecDeque<Record>,
+    start: Instant,
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    pending_input: Vec<InputEvent>,
+}
+
+/// The recording and replay state of an application
+#[derive(Debug, Default)]
+pub(crate) struct RecordingState {
+    recorder: Option<Recorder>,
+    replay: Option<Replay>,
+}
+
+impl RecordingState {
+    pub(crate) fn start_recording(&mut self, path: &Path) -> io::Result<()> {
+        self.stop_recording()?;
+        self.recorder = Some(Recorder::create(path)?);
+        Ok(())
+    }
+
+    pub(crate) fn stop_recording(&mut self) -> io::Result<()> {
+        match self.recorder.take() {
+            Some(mut recorder) => recorder.encoder.writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    pub(crate) fn record(&mut self, event: &RecordedEvent) {
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(err) = recorder.record(event) {
+                error!("Failed to record event, stopping recording: {err}");
+                self.recorder = None;
+            }
+        }
+    }
+
+    pub(crate) fn start_replay(&mut self, recording: Recording) {
+        self.replay = Some(Replay {
+            records: recording.records.into(),
+            start: Instant::now(),
+            #[cfg(any(feature = "game-activity", feature = "test"))]
+            pending_input: vec![],
+        });
+    }
+
+    pub(crate) fn stop_replay(&mut self) {
+        self.replay = None;
+    }
+
+    pub(crate) fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Returns how long until the next replayed event is due, or `None` once the
+    /// end of the recording has been reached (which ends the replay)
+    pub(crate) fn time_until_next_replay(&mut self) -> Option<Duration> {
+        let replay = self.replay.as_ref()?;
+        match replay.records.front() {
+            Some(record) => Some(record.time.saturating_sub(replay.start.elapsed())),
+            None => {
+                self.replay = None;
+                None
+            }
+        }
+    }
+
+    /// Takes the next replayed event, if it's due
+    ///
+    /// Input events are buffered until the next call to
+    /// [`AndroidApp::input_events()`] and replayed FD, timer, user and frame events
+    /// are skipped.
+    pub(crate) fn next_replayed_event(&mut self) -> Option<RecordedEvent> {
+        let replay = self.replay.as_mut()?;
+        while replay.records.front().is_some_and(|record| record.time <= replay.start.elapsed()) {
+            match replay.records.pop_front().unwrap().event {
+                #[cfg(any(feature = "game-activity", feature = "test"))]
+                RecordedEvent::Input(event) => replay.pending_input.push(*event),
+                RecordedEvent::Fd { .. } | RecordedEvent::Timer(_) | RecordedEvent::User | RecordedEvent::Frame { .. } => {}
+                event => return Some(event),
+            }
+        }
+        None
+    }
+
+    /// Takes the replayed input events for a call to `input_events()`
+    ///
+    /// This includes the input events that come next in the recording, even if
+    /// they aren't due yet, since they would have been recorded by the same
+    /// `input_events()` call, soon after the application handled the last event.
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    pub(crate) fn take_replayed_input(&mut self) -> Vec<InputEvent> {
+        let replay = match self.replay.as_mut() {
+            Some(replay) => replay,
+            None => return vec![],
+        };
+        while let Some(Record { event: RecordedEvent::Input(_), .. }) = replay.records.front() {
+            if let Some(Record { event: RecordedEvent::Input(event), .. }) = replay.records.pop_front() {
+                replay.pending_input.push(*event);
+            }
+        }
+        std::mem::take(&mut replay.pending_input)
+    }
+}
+
+impl RecordedEvent {
+    /// Makes an owned copy of an event before it's passed to the application
+    pub(crate) fn from_poll_event(app: &AndroidApp, event: &PollEvent) -> Self {
+        match event {
+            PollEvent::Wake => RecordedEvent::Wake,
+            PollEvent::Timeout => RecordedEvent::Timeout,
+            PollEvent::Error => RecordedEvent::Error,
+            PollEvent::Fd { token, events } => RecordedEvent::Fd { token: *token, events: *events },
+            PollEvent::Timer(id) => RecordedEvent::Timer(*id),
+            PollEvent::User(_) => RecordedEvent::User,
+            PollEvent::Frame { frame_time_nanos, vsync_id } => RecordedEvent::Frame { frame_time_nanos: *frame_time_nanos, vsync_id: *vsync_id },
+            PollEvent::Main(main_event) => match main_event {
+                MainEvent::InitWindow { .. } => {
+                    let Size { width, height } = app.inner.poll_state().window_size();
+                    RecordedEvent::InitWindow { width, height }
+                }
+                MainEvent::TerminateWindow { .. } => RecordedEvent::TerminateWindow,
+                MainEvent::WindowResized { old_size, new_size } => RecordedEvent::WindowResized { old_size: *old_size, new_size: *new_size },
+                MainEvent::RedrawNeeded { .. } => RecordedEvent::RedrawNeeded,
+                MainEvent::ContentRectChanged { rect } => RecordedEvent::ContentRectChanged(rect.clone()),
+                MainEvent::GainedFocus => RecordedEvent::GainedFocus,
+                MainEvent::LostFocus => RecordedEvent::LostFocus,
+                MainEvent::ConfigChanged { diff, .. } => RecordedEvent::ConfigChanged(*diff),
+                MainEvent::LowMemory { level } => RecordedEvent::LowMemory(*level),
+                MainEvent::Start => RecordedEvent::Start,
+                MainEvent::Resume { loader } => RecordedEvent::Resume { saved_state: loader.load() },
+                MainEvent::SaveState { .. } => RecordedEvent::SaveState,
+                MainEvent::Pause => RecordedEvent::Pause,
+                MainEvent::Stop => RecordedEvent::Stop,
+                MainEvent::Destroy => RecordedEvent::Destroy,
+                MainEvent::InsetsChanged { insets } => RecordedEvent::InsetsChanged(insets.clone()),
+            },
+        }
+    }
+
+    /// Converts a replayed event back into the event that's passed to the application
+    ///
+    /// Returns `None` for input, FD, timer, user and frame events, which aren't
+    /// replayed via `poll_events()`
+    pub(crate) fn to_poll_event<'a>(&'a self, app: &AndroidApp) -> Option<PollEvent<'a>> {
+        let main_event = match self {
+            RecordedEvent::Wake => return Some(PollEvent::Wake),
+            RecordedEvent::Timeout => return Some(PollEvent::Timeout),
+            RecordedEvent::Error => return Some(PollEvent::Error),
+            RecordedEvent::Fd { .. } | RecordedEvent::Timer(_) | RecordedEvent::User | RecordedEvent::Frame { .. } => return None,
+            #[cfg(any(feature = "game-activity", feature = "test"))]
+            RecordedEvent::Input(_) => return None,
+            RecordedEvent::InitWindow { .. } => MainEvent::InitWindow {},
+            RecordedEvent::TerminateWindow => MainEvent::TerminateWindow {},
+            RecordedEvent::WindowResized { old_size, new_size } => MainEvent::WindowResized { old_size: *old_size, new_size: *new_size },
+            RecordedEvent::RedrawNeeded => MainEvent::RedrawNeeded {},
+            RecordedEvent::ContentRectChanged(rect) => MainEvent::ContentRectChanged { rect: rect.clone() },
+            RecordedEvent::GainedFocus => MainEvent::GainedFocus,
+            RecordedEvent::LostFocus => MainEvent::LostFocus,
+            RecordedEvent::ConfigChanged(diff) => MainEvent::ConfigChanged { diff: *diff, old: app.config(), new: app.config() },
+            RecordedEvent::LowMemory(level) => MainEvent::LowMemory { level: *level },
+            RecordedEvent::Start => MainEvent::Start,
+            RecordedEvent::Resume { saved_state } => MainEvent::Resume {
+                loader: crate::StateLoader::replayed(saved_state.as_deref()),
+            },
+            RecordedEvent::SaveState => MainEvent::SaveState { saver: crate::StateSaver::replayed() },
+            RecordedEvent::Pause => MainEvent::Pause,
+            RecordedEvent::Stop => MainEvent::Stop,
+            RecordedEvent::Destroy => MainEvent::Destroy,
+            RecordedEvent::InsetsChanged(insets) => MainEvent::InsetsChanged { insets: insets.clone() },
+        };
+        Some(PollEvent::Main(main_event))
+    }
+}
+
+// Records are encoded as a varint time delta (in nanoseconds) followed by a tag
+// byte and the event's fields, with integers encoded as (zigzag) varints
+mod tag {
+    pub const WAKE: u8 = 0;
+    pub const TIMEOUT: u8 = 1;
+    pub const ERROR: u8 = 2;
+    pub const FD_EVENT: u8 = 3;
+    pub const INIT_WINDOW: u8 = 4;
+    pub const TERMINATE_WINDOW: u8 = 5;
+    pub const WINDOW_RESIZED: u8 = 6;
+    pub const REDRAW_NEEDED: u8 = 7;
+    pub const CONTENT_RECT_CHANGED: u8 = 8;
+    pub const GAINED_FOCUS: u8 = 9;
+    pub const LOST_FOCUS: u8 = 10;
+    pub const CONFIG_CHANGED: u8 = 11;
+    pub const LOW_MEMORY: u8 = 12;
+    pub const START: u8 = 13;
+    pub const RESUME: u8 = 14;
+    pub const SAVE_STATE: u8 = 15;
+    pub const PAUSE: u8 = 16;
+    pub const STOP: u8 = 17;
+    pub const DESTROY: u8 = 18;
+    pub const INSETS_CHANGED: u8 = 19;
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    pub const KEY_EVENT: u8 = 20;
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    pub const MOTION_EVENT: u8 = 21;
+    pub const TIMER: u8 = 22;
+    pub const USER: u8 = 23;
+    pub const FRAME: u8 = 24;
+}
+
+struct Encoder<W> {
+    writer: W,
+}
+
+impl<W: Write> Encoder<W> {
+    fn u8(&mut self, value: u8) -> io::Result<()> {
+        self.writer.write_all(&[value])
+    }
+
+    fn varint(&mut self, mut value: u64) -> io::Result<()> {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                return self.u8(byte);
+            }
+            self.u8(byte | 0x80)?;
+        }
+    }
+
+    fn int(&mut self, value: i64) -> io::Result<()> {
+        self.varint(((value << 1) ^ (value >> 63)) as u64)
+    }
+
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    fn f32(&mut self, value: f32) -> io::Result<()> {
+        self.writer.write_all(&value.to_le_bytes())
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.varint(bytes.len() as u64)?;
+        self.writer.write_all(bytes)
+    }
+
+    fn rect(&mut self, rect: &Rect) -> io::Result<()> {
+        for value in [rect.left, rect.top, rect.right, rect.bottom] {
+            self.int(value as i64)?;
+        }
+        Ok(())
+    }
+
+    fn event(&mut self, event: &RecordedEvent) -> io::Result<()> {
+        match event {
+            RecordedEvent::Wake => self.u8(tag::WAKE),
+            RecordedEvent::Timeout => self.u8(tag::TIMEOUT),
+            RecordedEvent::Error => self.u8(tag::ERROR),
+            RecordedEvent::Fd { token, events } => {
+                self.u8(tag::FD_EVENT)?;
+                self.int(token.ident() as i64)?;
+                self.varint(events.bits() as u64)
+            }
+            RecordedEvent::Timer(id) => {
+                self.u8(tag::TIMER)?;
+                self.int(id.ident() as i64)
+            }
+            RecordedEvent::User => self.u8(tag::USER),
+            RecordedEvent::Frame { frame_time_nanos, vsync_id } => {
+                self.u8(tag::FRAME)?;
+                self.int(*frame_time_nanos)?;
+                match vsync_id {
+                    Some(vsync_id) => {
+                        self.u8(1)?;
+                        self.int(*vsync_id)
+                    }
+                    None => self.u8(0),
+                }
+            }
+            RecordedEvent::InitWindow { width, height } => {
+                self.u8(tag::INIT_WINDOW)?;
+                self.int(*width as i64)?;
+                self.int(*height as i64)
+            }
+            RecordedEvent::TerminateWindow => self.u8(tag::TERMINATE_WINDOW),
+            RecordedEvent::WindowResized { old_size, new_size } => {
+                self.u8(tag::WINDOW_RESIZED)?;
+                for value in [old_size.width, old_size.height, new_size.width, new_size.height] {
+                    self.int(value as i64)?;
+                }
+                Ok(())
+            }
+            RecordedEvent::RedrawNeeded => self.u8(tag::REDRAW_NEEDED),
+            RecordedEvent::ContentRectChanged(rect) => {
+                self.u8(tag::CONTENT_RECT_CHANGED)?;
+                self.rect(rect)
+            }
+            RecordedEvent::GainedFocus => self.u8(tag::GAINED_FOCUS),
+            RecordedEvent::LostFocus => self.u8(tag::LOST_FOCUS),
+            RecordedEvent::ConfigChanged(diff) => {
+                self.u8(tag::CONFIG_CHANGED)?;
+                self.varint(diff.bits() as u64)
+            }
+            RecordedEvent::LowMemory(level) => {
+                self.u8(tag::LOW_MEMORY)?;
+                self.int(level.to_raw() as i64)
+            }
+            RecordedEvent::Start => self.u8(tag::START),
+            RecordedEvent::Resume { saved_state } => {
+                self.u8(tag::RESUME)?;
+                match saved_state {
+                    Some(state) => {
+                        self.u8(1)?;
+                        self.bytes(state)
+                    }
+                    None => self.u8(0),
+                }
+            }
+            RecordedEvent::SaveState => self.u8(tag::SAVE_STATE),
+            RecordedEvent::Pause => self.u8(tag::PAUSE),
+            RecordedEvent::Stop => self.u8(tag::STOP),
+            RecordedEvent::Destroy => self.u8(tag::DESTROY),
+            RecordedEvent::InsetsChanged(insets) => {
+                self.u8(tag::INSETS_CHANGED)?;
+                for (_, rect) in insets.iter() {
+                    self.rect(rect)?;
+                }
+                Ok(())
+            }
+            #[cfg(any(feature = "game-activity", feature = "test"))]
+            RecordedEvent::Input(event) => self.input_event(event),
+        }
+    }
+
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    fn input_event(&mut self, event: &InputEvent) -> io::Result<()> {
+        match event {
+            InputEvent::KeyEvent(key_event) => {
+                let event: &GameActivityKeyEvent = key_event;
+                self.u8(tag::KEY_EVENT)?;
+                for value in [event.deviceId, event.source, event.action] {
+                    self.int(value as i64)?;
+                }
+                self.int(event.eventTime)?;
+                self.int(event.downTime)?;
+                for value in [event.flags, event.metaState, event.modifiers, event.repeatCount, event.keyCode, event.scanCode] {
+                    self.int(value as i64)?;
+                }
+                Ok(())
+            }
+            InputEvent::MotionEvent(motion_event) => {
+                let event: &GameActivityMotionEvent = motion_event;
+                self.u8(tag::MOTION_EVENT)?;
+                for value in [event.deviceId, event.source, event.action] {
+                    self.int(value as i64)?;
+                }
+                self.int(event.eventTime)?;
+                self.int(event.downTime)?;
+                for value in [event.flags, event.metaState, event.actionButton, event.buttonState, event.classification, event.edgeFlags] {
+                    self.int(value as i64)?;
+                }
+                self.f32(event.precisionX)?;
+                self.f32(event.precisionY)?;
+
+                // Most axis values are zero, so only the non-zero values are
+                // written, following a bit mask of which axes are present
+                let pointer_count = (event.pointerCount as usize).min(event.pointers.len());
+                self.varint(pointer_count as u64)?;
+                for pointer in &event.pointers[..pointer_count] {
+                    self.int(pointer.id as i64)?;
+                    self.f32(pointer.rawX)?;
+                    self.f32(pointer.rawY)?;
+                    let mask = pointer.axisValues.iter().enumerate()
+                        .filter(|(_, value)| **value != 0.0)
+                        .fold(0u64, |mask, (axis, _)| mask | (1 << axis));
+                    self.varint(mask)?;
+                    for value in pointer.axisValues.iter().filter(|value| **value != 0.0) {
+                        self.f32(*value)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+struct Decoder<R> {
+    reader: R,
+}
+
+impl<R: Read> Decoder<R> {
+    fn u8(&mut self) -> io::Result<u8> {
+        let mut byte = [0u8];
+        self.reader.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+
+    fn varint(&mut self) -> io::Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid varint in event recording"))
+    }
+
+    fn int(&mut self) -> io::Result<i64> {
+        let value = self.varint()?;
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    fn i32(&mut self) -> io::Result<i32> {
+        i32::try_from(self.int()?).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Invalid integer in event recording"))
+    }
+
+    #[cfg(any(feature = "game-activity", feature = "test"))]
+    fn f32(&mut self) -> io::Result<f32> {
+        let mut bytes = [0u8; 4];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(f32::from_le_bytes(bytes))
+    }
+
+    fn bytes(&mut self) -> io::Result<Vec<u8>> {
+        let len = self.varint()? as usize;
+        let mut bytes = vec![];
+        self.reader.by_ref().take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(bytes)
+    }
+
+    fn rect(&mut self) -> io::Result<Rect> {
+        Ok(Rect { left: self.i32()?, top: self.i32()?, right: self.i32()?, bottom: self.i32()? })
+    }
+
+    fn event(&mut self) -> io::Result<RecordedEvent> {
+        let event = match self.u8()? {
+            tag::WAKE => RecordedEvent::Wake,
+            tag::TIMEOUT => RecordedEvent::Timeout,
+            tag::ERROR => RecordedEvent::Error,
+            tag::FD_EVENT => RecordedEvent::Fd {
+                token: FdToken::from_ident(self.i32()?),
+                events: FdEvent::from_bits_truncate(self.varint()? as u32),
+            },
+            tag::TIMER => RecordedEvent::Timer(TimerId::from_ident(self.i32()?)),
+            tag::USER => RecordedEvent::User,
+            tag::FRAME => RecordedEvent::Frame {
+                frame_time_nanos: self.int()?,
+                vsync_id: if self.u8()? != 0 { Some(self.int()?) } else { None },
+            },
+            tag::INIT_WINDOW => RecordedEvent::InitWindow { width: self.i32()?, height: self.i32()? },
+            tag::TERMINATE_WINDOW => RecordedEvent::TerminateWindow,
+            tag::WINDOW_RESIZED => RecordedEvent::WindowResized {
+                old_size: Size { width: self.i32()?, height: self.i32()? },
+                new_size: Size { width: self.i32()?, height: self.i32()? },
+            },
+            tag::REDRAW_NEEDED => RecordedEvent::RedrawNeeded,
+            tag::CONTENT_RECT_CHANGED => RecordedEvent::ContentRectChanged(self.rect()?),
+            tag::GAINED_FOCUS => RecordedEvent::GainedFocus,
+            tag::LOST_FOCUS => RecordedEvent::LostFocus,
+            tag::CONFIG_CHANGED => RecordedEvent::ConfigChanged(ConfigurationDiff::from_bits_truncate(self.varint()? as u32)),
+            tag::LOW_MEMORY => RecordedEvent::LowMemory(TrimMemoryLevel::from_raw(self.i32()?)),
+            tag::START => RecordedEvent::Start,
+            tag::RESUME => RecordedEvent::Resume {
+                saved_state: match self.u8()? {
+                    0 => None,
+                    _ => Some(self.bytes()?),
+                },
+            },
+            tag::SAVE_STATE => RecordedEvent::SaveState,
+            tag::PAUSE => RecordedEvent::Pause,
+            tag::STOP => RecordedEvent::Stop,
+            tag::DESTROY => RecordedEvent::Destroy,
+            tag::INSETS_CHANGED => {
+                let mut insets = WindowInsets::default();
+                for inset_type in InsetType::ALL {
+                    insets.set(inset_type, self.rect()?);
+                }
+                RecordedEvent::InsetsChanged(insets)
+            }
+            #[cfg(any(feature = "game-activity", feature = "test"))]
+            tag::KEY_EVENT => RecordedEvent::Input(Box::new(InputEvent::KeyEvent(KeyEvent::new(GameActivityKeyEvent {
+                deviceId: self.i32()?,
+                source: self.i32()?,
+                action: self.i32()?,
+                eventTime: self.int()?,
+                downTime: self.int()?,
+                flags: self.i32()?,
+                metaState: self.i32()?,
+                modifiers: self.i32()?,
+                repeatCount: self.i32()?,
+                keyCode: self.i32()?,
+                scanCode: self.i32()?,
+            })))),
+            #[cfg(any(feature = "game-activity", feature = "test"))]
+            tag::MOTION_EVENT => {
+                let mut event = GameActivityMotionEvent {
+                    deviceId: self.i32()?,
+                    source: self.i32()?,
+                    action: self.i32()?,
+                    eventTime: self.int()?,
+                    downTime: self.int()?,
+                    flags: self.i32()?,
+                    metaState: self.i32()?,
+                    actionButton: self.i32()?,
+                    buttonState: self.i32()?,
+                    classification: self.i32()?,
+                    edgeFlags: self.i32()?,
+                    precisionX: self.f32()?,
+                    precisionY: self.f32()?,
+                    pointerCount: 0,
+                    pointers: [GameActivityPointerAxes { id: 0, axisValues: [0.0; 48], rawX: 0.0, rawY: 0.0 }; 8],
+                };
+                let pointer_count = self.varint()? as usize;
+                if pointer_count > event.pointers.len() {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, "Too many pointers in recorded motion event"));
+                }
+                event.pointerCount = pointer_count as u32;
+                for pointer in &mut event.pointers[..pointer_count] {
+                    pointer.id = self.i32()?;
+                    pointer.rawX = self.f32()?;
+                    pointer.rawY = self.f32()?;
+                    let mask = self.varint()?;
+                    for (axis, value) in pointer.axisValues.iter_mut().enumerate() {
+                        if mask & (1 << axis) != 0 {
+                            *value = self.f32()?;
+                        }
+                    }
+                }
+                RecordedEvent::Input(Box::new(InputEvent::MotionEvent(MotionEvent::new(event))))
+            }
+            tag => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unknown event tag {tag} in event recording"))),
+        };
+        Ok(event)
+    }
+}
@@ -0,0 +1,196 @@
+//! Versioned, `serde` based saved state (requires the `serde` feature)
+//!
+//! [`StateSaver::store_serialized()`] serializes the application's state and
+//! prefixes it with a small header that records a schema version, chosen by the
+//! application, and a checksum of the serialized state:
+//!
+//! | Offset | Size | Contents                                       |
+//! |--------|------|------------------------------------------------|
+//! | 0      | 4    | `b"AAST"`                                      |
+//! | 4      | 4    | Schema version (little endian)                 |
+//! | 8      | 4    | CRC-32 of the serialized state (little endian) |
+//! | 12     | ..   | The state, serialized as JSON                  |
+//!
+//! [`StateLoader::load_deserialized()`] then validates the header before the state is
+//! deserialized, so that a truncated or corrupt bundle, or one that was saved by an
+//! older (or newer) version of the application, is reported as a [`SavedStateError`].
+//! State that was saved with an older schema version can be migrated via
+//! [`StateLoader::load_deserialized_with()`].
+//!
+//! ```no_run
+//! # use android_activity::{MainEvent, SavedState};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct StateV1 { uri: String }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct State { uri: String, zoom: f32 }
+//!
+//! const STATE_VERSION: u32 = 2;
+//!
+//! fn handle(event: MainEvent) {
+//!     match event {
+//!         MainEvent::SaveState { saver, .. } => {
+//!             let state = State { uri: "foo://bar".to_string(), zoom: 1.0 };
+//!             saver.store_serialized(STATE_VERSION, &state).unwrap();
+//!         }
+//!         MainEvent::Resume { loader, .. } => {
+//!             let state = loader.load_deserialized_with(STATE_VERSION, |old: SavedState| match old.version() {
+//!                 1 => old.deserialize::<StateV1>().map(|v1| State { uri: v1.uri, zoom: 1.0 }),
+//!                 _ => Err(old.unsupported()),
+//!             });
+//!             match state {
+//!                 Ok(Some(state)) => { /* restore */ }
+//!                 Ok(None) => { /* fresh start */ }
+//!                 Err(err) => log::warn!("Discarding saved state: {err}"),
+//!             }
+//!         }
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::state_spill::crc32;
+use crate::{StateLoader, StateSaver};
+
+const MAGIC: &[u8; 4] = b"AAST";
+const HEADER_LEN: usize = 12;
+
+/// The error returned when saved state can't be stored or loaded
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SavedStateError {
+    /// The saved state wasn't stored via [`StateSaver::store_serialized()`], or is truncated
+    InvalidHeader,
+
+    /// The saved state doesn't match its checksum
+    ChecksumMismatch,
+
+    /// The saved state has a schema version that's newer than the application's, or
+    /// older without a migration
+    UnsupportedVersion { version: u32, expected: u32 },
+
+    /// The state couldn't be serialized, or deserialized as the expected type
+    Serde(serde_json::Error),
+}
+
+impl fmt::Display for SavedStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SavedStateError::InvalidHeader => write!(f, "The saved state has an invalid header"),
+            SavedStateError::ChecksumMismatch => write!(f, "The saved state doesn't match its checksum"),
+            SavedStateError::UnsupportedVersion { version, expected } => {
+                write!(f, "The saved state has version {version}, which can't be loaded as version {expected}")
+            }
+            SavedStateError::Serde(err) => write!(f, "Failed to (de)serialize the saved state: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SavedStateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SavedStateError::Serde(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for SavedStateError {
+    fn from(err: serde_json::Error) -> Self {
+        SavedStateError::Serde(err)
+    }
+}
+
+/// Saved state with an older schema version, as passed to the migration hook of
+/// [`StateLoader::load_deserialized_with()`]
+#[derive(Debug)]
+pub struct SavedState<'a> {
+    version: u32,
+    expected: u32,
+    state: &'a [u8],
+}
+
+impl<'a> SavedState<'a> {
+    /// The schema version that the state was saved with
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Deserializes the state as the type that was saved with this version
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, SavedStateError> {
+        Ok(serde_json::from_slice(self.state)?)
+    }
+
+    /// The error to return for a version that can't be migrated
+    pub fn unsupported(&self) -> SavedStateError {
+        SavedStateError::UnsupportedVersion { version: self.version, expected: self.expected }
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+impl<'a> StateSaver<'a> {
+    /// Serializes `state` with a header that records the given schema `version`
+    /// and a checksum, so it can be loaded via [`StateLoader::load_deserialized()`]
+    pub fn store_serialized<T: Serialize + ?Sized>(&self, version: u32, state: &T) -> Result<(), SavedStateError> {
+        let state = serde_json::to_vec(state)?;
+        let mut buf = Vec::with_capacity(HEADER_LEN + state.len());
+        buf.extend_from_slice(MAGIC);
+        buf.extend_from_slice(&version.to_le_bytes());
+        buf.extend_from_slice(&crc32(&state).to_le_bytes());
+        buf.extend_from_slice(&state);
+        self.store(&buf);
+        Ok(())
+    }
+}
+
+impl<'a> StateLoader<'a> {
+    /// Loads state that was stored via [`StateSaver::store_serialized()`] with the
+    /// same schema `version`
+    ///
+    /// Returns `Ok(None)` if there is no saved state.
+    pub fn load_deserialized<T: DeserializeOwned>(&self, version: u32) -> Result<Option<T>, SavedStateError> {
+        self.load_deserialized_with(version, |old| Err(old.unsupported()))
+    }
+
+    /// Like [`StateLoader::load_deserialized()`] but state that was saved with an
+    /// older schema version is passed to `migrate`, which can deserialize it as the
+    /// type for that version and convert it
+    ///
+    /// The checksum is validated before `migrate` is called. State that was saved
+    /// with a newer schema version, such as after the application has been
+    /// downgraded, is always rejected.
+    pub fn load_deserialized_with<T, F>(&self, version: u32, migrate: F) -> Result<Option<T>, SavedStateError>
+        where T: DeserializeOwned,
+              F: FnOnce(SavedState<'_>) -> Result<T, SavedStateError>
+    {
+        let buf = match self.load() {
+            Some(buf) => buf,
+            None => return Ok(None),
+        };
+        if buf.len() < HEADER_LEN || &buf[0..4] != MAGIC {
+            return Err(SavedStateError::InvalidHeader);
+        }
+        let saved_version = read_u32(&buf[4..8]);
+        let state = &buf[HEADER_LEN..];
+        if read_u32(&buf[8..12]) != crc32(state) {
+            return Err(SavedStateError::ChecksumMismatch);
+        }
+
+        if saved_version == version {
+            Ok(Some(serde_json::from_slice(state)?))
+        } else if saved_version < version {
+            migrate(SavedState { version: saved_version, expected: version, state }).map(Some)
+        } else {
+            Err(SavedStateError::UnsupportedVersion { version: saved_version, expected: version })
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Spilling of large saved state to a file, outside of the Activity's `Bundle`
+//!
+//! Saved state is passed to the Java Activity in a `Bundle`, which is sent via
+//! Binder and a large bundle can fail with a `TransactionTooLargeException` that
+//! kills the process. State that's larger than a threshold is instead written to
+//! a file in the application's internal data directory and the bundle only holds
+//! a small handle:
+//!
+//! | Offset | Size | Contents                                       |
+//! |--------|------|------------------------------------------------|
+//! | 0      | 8    | `b"AASPILL\0"`                                 |
+//! | 8      | 8    | Length of the spilled state (little endian)    |
+//! | 16     | 4    | CRC-32 of the spilled state (little endian)    |
+//! | 20     | ..   | The name of the file within the data directory |
+//!
+//! When the handle is loaded, the state is read back from the file and validated
+//! against the handle. The file isn't deleted then, since the Activity restores
+//! the same bundle again if the process is killed before it saves new state.
+//! Instead each new save deletes the previous file (whether it was written or
+//! restored by this process), since the Activity only restores from its most
+//! recent bundle, which either refers to a new file or holds the state itself.
+//!
+//! Spilled state that's never loaded, such as when the process is killed after
+//! saving its state and the Activity isn't restored, would otherwise be left
+//! behind. Files are named after the process that wrote them, so on startup any
+//! files from other processes are deleted, except for the one that's about to
+//! be restored.
+
+use log::{debug, error, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 8] = b"AASPILL\0";
+const HEADER_LEN: usize = 20;
+const FILE_PREFIX: &str = "android-activity-saved-state-";
+
+/// The default threshold (in bytes) above which saved state is spilled
+///
+/// Android recommends keeping saved instance state well below the 1MB Binder
+/// transaction limit, which is shared by all of the process's transactions.
+pub(crate) const DEFAULT_SPILL_THRESHOLD: usize = 64 * 1024;
+
+// The CRC-32 (IEEE 802.3) of `data`
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+#[derive(Debug)]
+pub(crate) struct StateSpill {
+    // The application's internal data directory, if known
+    dir: Option<PathBuf>,
+
+    // `usize::MAX` if spilling is disabled
+    threshold: AtomicUsize,
+
+    // The file that the most recent handle refers to, which was either written
+    // by the last spill or restored, and is deleted by the next save
+    last_spill: Mutex<Option<PathBuf>>,
+}
+
+// Returns the name of the file that a handle refers to, or `None` if `state`
+// isn't a handle, or `Some(Err(()))` if the handle is invalid
+fn handle_file_name(state: &[u8]) -> Option<Result<&str, ()>> {
+    if state.len() < HEADER_LEN || &state[0..8] != MAGIC {
+        return None;
+    }
+    Some(match std::str::from_utf8(&state[HEADER_LEN..]) {
+        // Only a plain file name within the data directory is accepted
+        Ok(name) if Path::new(name).file_name().is_some_and(|file_name| file_name == name) => Ok(name),
+        _ => Err(()),
+    })
+}
+
+// Deletes spilled state that was written by other processes and never loaded,
+// except for the file that `saved_state` refers to
+fn remove_stale_spills(dir: &Path, saved_state: Option<&[u8]>) {
+    let restoring = saved_state.and_then(handle_file_name).and_then(Result::ok);
+    let own_prefix = format!("{FILE_PREFIX}{}-", std::process::id());
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = match name.to_str() {
+            Some(name) => name,
+            None => continue,
+        };
+        if name.starts_with(FILE_PREFIX) && !name.starts_with(&own_prefix) && Some(name) != restoring {
+            debug!("Deleting stale spilled saved state {name}");
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+impl StateSpill {
+    /// Creates the spill state for an application that's restoring `saved_state`,
+    /// deleting any stale spill files in `dir`
+    pub(crate) fn new(dir: Option<PathBuf>, saved_state: Option<&[u8]>) -> Self {
+        if let Some(dir) = &dir {
+            remove_stale_spills(dir, saved_state);
+        }
+        Self {
+            dir,
+            threshold: AtomicUsize::new(DEFAULT_SPILL_THRESHOLD),
+            last_spill: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn set_threshold(&self, threshold: Option<usize>) {
+        self.threshold.store(threshold.unwrap_or(usize::MAX), Ordering::SeqCst);
+    }
+
+    /// Returns the handle to store instead of `state`, or `None` if `state`
+    /// should be stored as is
+    ///
+    /// Either way, the file that the previously stored handle referred to is
+    /// deleted.
+    pub(crate) fn spill(&self, state: &[u8]) -> Option<Vec<u8>> {
+        if state.len() <= self.threshold.load(Ordering::SeqCst) {
+            self.remove_last_spill();
+            return None;
+        }
+        let dir = match &self.dir {
+            Some(dir) => dir,
+            None => {
+                warn!("Storing {} bytes of saved state without spilling, since there's no data directory", state.len());
+                self.remove_last_spill();
+                return None;
+            }
+        };
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+        let name = format!("{FILE_PREFIX}{}-{nanos}", std::process::id());
+        let path = dir.join(&name);
+        if let Err(err) = fs::create_dir_all(dir).and_then(|_| fs::write(&path, state)) {
+            error!("Failed to spill {} bytes of saved state to {path:?}: {err}", state.len());
+            let _ = fs::remove_file(&path);
+            self.remove_last_spill();
+            return None;
+        }
+        if let Some(previous) = self.last_spill.lock().unwrap().replace(path) {
+            let _ = fs::remove_file(previous);
+        }
+
+        let mut handle = Vec::with_capacity(HEADER_LEN + name.len());
+        handle.extend_from_slice(MAGIC);
+        handle.extend_from_slice(&(state.len() as u64).to_le_bytes());
+        handle.extend_from_slice(&crc32(state).to_le_bytes());
+        handle.extend_from_slice(name.as_bytes());
+        Some(handle)
+    }
+
+    // Deletes the file that the previously stored handle referred to, once the
+    // state has been stored without a handle
+    fn remove_last_spill(&self) {
+        if let Some(previous) = self.last_spill.lock().unwrap().take() {
+            let _ = fs::remove_file(previous);
+        }
+    }
+
+    /// Reassembles state that was stored as a handle by [`StateSpill::spill()`],
+    /// or returns `state` as is if it's not a handle
+    ///
+    /// The file is kept until the next spill replaces it. Returns `None` if the
+    /// spilled state is missing or corrupt.
+    pub(crate) fn unspill(&self, state: Vec<u8>) -> Option<Vec<u8>> {
+        let name = match handle_file_name(&state) {
+            None => return Some(state),
+            Some(Ok(name)) => name,
+            Some(Err(())) => {
+                error!("Ignoring saved state with an invalid spill handle");
+                return None;
+            }
+        };
+        let len = u64::from_le_bytes(state[8..16].try_into().unwrap());
+        let crc = u32::from_le_bytes(state[16..20].try_into().unwrap());
+        let path = self.dir.as_ref()?.join(name);
+
+        match fs::read(&path) {
+            Ok(spilled) if spilled.len() as u64 == len && crc32(&spilled) == crc => {
+                self.last_spill.lock().unwrap().get_or_insert(path);
+                Some(spilled)
+            }
+            Ok(_) => {
+                error!("Ignoring spilled saved state in {path:?} that doesn't match its handle");
+                let _ = fs::remove_file(&path);
+                None
+            }
+            Err(err) => {
+                error!("Failed to read spilled saved state from {path:?}: {err}");
+                None
+            }
+        }
+    }
+}
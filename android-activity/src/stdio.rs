@@ -0,0 +1,202 @@
+//! Redirection of stdout and stderr to logcat
+//!
+//! Anything that's written to stdout or stderr is discarded on Android, so with
+//! the `stdio-redirect` feature (enabled by default) the glue layer redirects them
+//! to logcat before `android_main` is called. Output is forwarded line by line,
+//! with any bytes that aren't valid UTF-8 replaced, and lines that are longer than
+//! [`StdioConfig::max_line_len`] are split.
+//!
+//! Applications that redirect stdio themselves can disable the `stdio-redirect`
+//! feature. The way that output is logged can be changed at any time via
+//! [`set_config()`].
+//!
+//! Note: output isn't redirected with the `test` backend.
+
+use log::Level;
+use std::sync::RwLock;
+
+/// How output to stdout and stderr is logged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StdioConfig {
+    /// The logcat tag for output from both stdout and stderr
+    pub tag: String,
+
+    /// The priority of lines written to stdout
+    pub stdout_level: Level,
+
+    /// The priority of lines written to stderr
+    pub stderr_level: Level,
+
+    /// The length (in bytes) after which a line without a newline is logged anyway
+    pub max_line_len: usize,
+
+    /// Whether a final line without a newline is logged after `android_main`
+    /// returns, or discarded
+    pub flush_on_exit: bool,
+}
+
+impl Default for StdioConfig {
+    fn default() -> Self {
+        Self {
+            tag: "RustStdoutStderr".to_string(),
+            stdout_level: Level::Info,
+            stderr_level: Level::Warn,
+            max_line_len: 4000,
+            flush_on_exit: true,
+        }
+    }
+}
+
+static CONFIG: RwLock<Option<StdioConfig>> = RwLock::new(None);
+
+/// Returns the current configuration
+pub fn config() -> StdioConfig {
+    CONFIG.read().unwrap().clone().unwrap_or_default()
+}
+
+/// Changes how output to stdout and stderr is logged, starting with the next line
+pub fn set_config(config: StdioConfig) {
+    *CONFIG.write().unwrap() = Some(config);
+}
+
+#[cfg(not(feature = "test"))]
+pub(crate) use self::redirect::Redirect;
+
+#[cfg(not(feature = "test"))]
+mod redirect {
+    use log::{error, warn};
+    use std::ffi::CString;
+    use std::fs::File;
+    use std::io::{self, BufRead, BufReader, Write};
+    use std::os::unix::prelude::*;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::config;
+    use crate::logger;
+
+    // How long to wait for any remaining output to be logged once `android_main`
+    // has returned, in case something else still holds a copy of stdout or stderr
+    const FLUSH_TIMEOUT: Duration = Duration::from_millis(200);
+
+    #[derive(Debug, Clone, Copy)]
+    enum Stream {
+        Stdout,
+        Stderr,
+    }
+
+    // Logs everything that's read from `reader` until it reaches the end of
+    // the file
+    fn forward(mut reader: impl BufRead, stream: Stream) {
+        let mut line = Vec::new();
+        loop {
+            let config = config();
+            let max_line_len = config.max_line_len.max(1);
+            let available = match reader.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    error!("Failed to read redirected {stream:?}: {err}");
+                    break;
+                }
+            };
+            let at_end = available.is_empty();
+            let room = available.len().min(max_line_len - line.len().min(max_line_len));
+            let complete = match available[..room].iter().position(|byte| *byte == b'\n') {
+                Some(newline) => {
+                    line.extend_from_slice(&available[..newline]);
+                    reader.consume(newline + 1);
+                    true
+                }
+                None => {
+                    line.extend_from_slice(&available[..room]);
+                    reader.consume(room);
+                    line.len() >= max_line_len || (at_end && config.flush_on_exit && !line.is_empty())
+                }
+            };
+
+            if complete {
+                let level = match stream {
+                    Stream::Stdout => config.stdout_level,
+                    Stream::Stderr => config.stderr_level,
+                };
+                let tag = CString::new(config.tag.replace('\0', "")).unwrap();
+                logger::write(level, &tag, &String::from_utf8_lossy(&line));
+                line.clear();
+            }
+            if at_end {
+                break;
+            }
+        }
+    }
+
+    // A redirected stream, along with the original file that it referred to
+    #[derive(Debug)]
+    struct Redirected {
+        fd: RawFd,
+        original: OwnedFd,
+        done: Receiver<()>,
+    }
+
+    /// Redirects stdout and stderr to logcat until [`Redirect::finish()`]
+    #[derive(Debug)]
+    pub(crate) struct Redirect {
+        streams: Vec<Redirected>,
+    }
+
+    impl Redirect {
+        pub(crate) fn start() -> io::Result<Self> {
+            let mut streams = Vec::new();
+            for (fd, stream) in [(libc::STDOUT_FILENO, Stream::Stdout), (libc::STDERR_FILENO, Stream::Stderr)] {
+                let mut pipe: [RawFd; 2] = Default::default();
+                let (read, write, original) = unsafe {
+                    if libc::pipe(pipe.as_mut_ptr()) != 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                    let (read, write) = (OwnedFd::from_raw_fd(pipe[0]), OwnedFd::from_raw_fd(pipe[1]));
+                    let original = libc::dup(fd);
+                    if original < 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                    (read, write, OwnedFd::from_raw_fd(original))
+                };
+
+                let (done_tx, done) = mpsc::channel();
+                thread::Builder::new()
+                    .name(format!("{stream:?}-to-logcat").to_lowercase())
+                    .spawn(move || {
+                        forward(BufReader::new(File::from(read)), stream);
+                        let _ = done_tx.send(());
+                    })?;
+
+                // Once `fd` is a copy of the pipe's write end, our copy is closed so
+                // that the reader sees the end of the file when `fd` is restored
+                if unsafe { libc::dup2(write.as_raw_fd(), fd) } < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                streams.push(Redirected { fd, original, done });
+            }
+            Ok(Self { streams })
+        }
+
+        /// Restores the original stdout and stderr, after logging any output
+        /// that's still buffered
+        pub(crate) fn finish(self) {
+            let _ = io::stdout().flush();
+            let _ = io::stderr().flush();
+            unsafe { libc::fflush(std::ptr::null_mut()) };
+
+            for stream in &self.streams {
+                unsafe { libc::dup2(stream.original.as_raw_fd(), stream.fd) };
+            }
+            let deadline = Instant::now() + FLUSH_TIMEOUT;
+            for stream in &self.streams {
+                if stream.done.recv_timeout(deadline.saturating_duration_since(Instant::now())).is_err() {
+                    warn!("Timed out flushing redirected output to logcat");
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! A host implementation of the NDK's `AAssetManager` API
+//!
+//! There is no `.apk` on the host, so the fake asset manager is always empty.
+
+use std::os::raw::{c_char, c_int};
+use std::ptr::{self, NonNull};
+
+use ndk_sys::{AAsset, AAssetDir, AAssetManager};
+
+// An `AAssetManager *` is never dereferenced, so we just need a unique, non-null address
+static ASSET_MANAGER: u8 = 0;
+
+pub(crate) fn asset_manager_ptr() -> NonNull<AAssetManager> {
+    NonNull::from(&ASSET_MANAGER).cast()
+}
+
+#[no_mangle]
+pub extern "C" fn AAssetManager_open(_mgr: *mut AAssetManager, _filename: *const c_char, _mode: c_int) -> *mut AAsset {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub extern "C" fn AAssetManager_openDir(_mgr: *mut AAssetManager, _dir_name: *const c_char) -> *mut AAssetDir {
+    ptr::null_mut()
+}
@@ -0,0 +1,117 @@
+//! A host implementation of the NDK's `AChoreographer` frame callbacks
+//!
+//! Like the real choreographer, there's one instance per thread which runs its
+//! callbacks via the thread's looper. The emulated display has a fixed 60Hz
+//! refresh rate and frame callbacks are run at the next vsync, via a `timerfd`
+//! that's registered as a looper callback.
+
+use std::cell::RefCell;
+use std::os::raw::{c_int, c_long, c_void};
+use std::os::unix::prelude::*;
+use std::ptr;
+
+use ndk_sys::{AChoreographer, AChoreographer_frameCallback};
+
+const VSYNC_PERIOD_NANOS: i64 = 16_666_667;
+
+pub(crate) struct FakeChoreographer {
+    timer: OwnedFd,
+    callbacks: RefCell<Vec<(AChoreographer_frameCallback, *mut c_void)>>,
+}
+
+thread_local! {
+    static THREAD_CHOREOGRAPHER: RefCell<Option<Box<FakeChoreographer>>> = const { RefCell::new(None) };
+}
+
+fn monotonic_nanos() -> i64 {
+    let mut now = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) };
+    let (secs, nanos): (i64, i64) = (now.tv_sec as _, now.tv_nsec as _);
+    secs * 1_000_000_000 + nanos
+}
+
+impl FakeChoreographer {
+    fn new() -> Option<Box<Self>> {
+        let looper = unsafe { ndk_sys::ALooper_forThread() };
+        if looper.is_null() {
+            return None;
+        }
+        let fd = unsafe { libc::timerfd_create(libc::CLOCK_MONOTONIC, libc::TFD_NONBLOCK | libc::TFD_CLOEXEC) };
+        if fd < 0 {
+            return None;
+        }
+        let choreographer = Box::new(Self {
+            timer: unsafe { OwnedFd::from_raw_fd(fd) },
+            callbacks: RefCell::new(vec![]),
+        });
+        unsafe {
+            ndk_sys::ALooper_addFd(
+                looper,
+                fd,
+                0,
+                ndk_sys::ALOOPER_EVENT_INPUT as c_int,
+                Some(on_vsync),
+                choreographer.as_ref() as *const FakeChoreographer as *mut c_void,
+            );
+        }
+        Some(choreographer)
+    }
+
+    // Arms the timer for the next vsync, at a multiple of the vsync period
+    fn schedule_vsync(&self) {
+        let now = monotonic_nanos();
+        let next = (now / VSYNC_PERIOD_NANOS + 1) * VSYNC_PERIOD_NANOS;
+        let spec = libc::itimerspec {
+            it_interval: libc::timespec { tv_sec: 0, tv_nsec: 0 },
+            it_value: libc::timespec { tv_sec: (next / 1_000_000_000) as _, tv_nsec: (next % 1_000_000_000) as _ },
+        };
+        unsafe { libc::timerfd_settime(self.timer.as_raw_fd(), libc::TFD_TIMER_ABSTIME, &spec, ptr::null_mut()) };
+    }
+}
+
+unsafe extern "C" fn on_vsync(fd: c_int, _events: c_int, data: *mut c_void) -> c_int {
+    let choreographer = &*(data as *const FakeChoreographer);
+    let mut expirations = 0u64;
+    libc::read(fd, &mut expirations as *mut u64 as *mut c_void, std::mem::size_of::<u64>());
+
+    // Callbacks that are posted while running these callbacks are for the next frame
+    let frame_time_nanos = monotonic_nanos() / VSYNC_PERIOD_NANOS * VSYNC_PERIOD_NANOS;
+    let callbacks = std::mem::take(&mut *choreographer.callbacks.borrow_mut());
+    for (callback, data) in callbacks {
+        if let Some(callback) = callback {
+            callback(frame_time_nanos as c_long, data);
+        }
+    }
+    1
+}
+
+#[no_mangle]
+pub extern "C" fn AChoreographer_getInstance() -> *mut AChoreographer {
+    THREAD_CHOREOGRAPHER.with(|choreographer| {
+        let mut choreographer = choreographer.borrow_mut();
+        if choreographer.is_none() {
+            *choreographer = FakeChoreographer::new();
+        }
+        choreographer
+            .as_ref()
+            .map(|choreographer| choreographer.as_ref() as *const FakeChoreographer as *mut AChoreographer)
+            .unwrap_or(ptr::null_mut())
+    })
+}
+
+/// # Safety
+/// `choreographer` must have been returned by [`AChoreographer_getInstance`] on
+/// the calling thread
+#[no_mangle]
+pub unsafe extern "C" fn AChoreographer_postFrameCallback(
+    choreographer: *mut AChoreographer,
+    callback: AChoreographer_frameCallback,
+    data: *mut c_void,
+) {
+    let choreographer = &*(choreographer as *const FakeChoreographer);
+    let mut callbacks = choreographer.callbacks.borrow_mut();
+    if callbacks.is_empty() {
+        choreographer.schedule_vsync();
+    }
+    callbacks.push((callback, data));
+}
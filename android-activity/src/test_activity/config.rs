@@ -0,0 +1,210 @@
+//! A host implementation of the NDK's `AConfiguration` API
+//!
+//! An `AConfiguration *` is simply a pointer to a heap allocated [`TestConfiguration`]
+//! which lets tests describe a device configuration in terms of the raw
+//! `ACONFIGURATION_*` values that `ndk::configuration::Configuration` expects.
+
+use std::os::raw::c_char;
+use std::ptr::NonNull;
+
+use ndk::configuration::Configuration;
+use ndk_sys::AConfiguration;
+
+/// The raw state of a fake `AConfiguration`
+///
+/// Each field holds the same value that the corresponding `AConfiguration_get*()`
+/// function would return on a device (see the `ACONFIGURATION_*` constants in
+/// `ndk_sys`). The default configuration has all fields set to their `*_ANY`
+/// value.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TestConfiguration {
+    pub mcc: i32,
+    pub mnc: i32,
+    pub language: [u8; 2],
+    pub country: [u8; 2],
+    pub orientation: i32,
+    pub touchscreen: i32,
+    pub density: i32,
+    pub keyboard: i32,
+    pub navigation: i32,
+    pub keys_hidden: i32,
+    pub nav_hidden: i32,
+    pub sdk_version: i32,
+    pub screen_size: i32,
+    pub screen_long: i32,
+    pub screen_round: i32,
+    pub ui_mode_type: i32,
+    pub ui_mode_night: i32,
+    pub screen_width_dp: i32,
+    pub screen_height_dp: i32,
+    pub smallest_screen_width_dp: i32,
+    pub layout_direction: i32,
+}
+
+impl TestConfiguration {
+    /// Creates a new `ndk` [`Configuration`] with the same contents
+    pub(crate) fn to_configuration(&self) -> Configuration {
+        let config = Configuration::new();
+        unsafe {
+            *config_mut(config.ptr().as_ptr()) = self.clone();
+        }
+        config
+    }
+
+    /// Returns a bitmask of `ACONFIGURATION_*` flags for the fields that differ
+    fn diff(&self, other: &Self) -> u32 {
+        let mut diff = 0;
+        let mut check = |changed: bool, flag: u32| {
+            if changed {
+                diff |= flag;
+            }
+        };
+        check(self.mcc != other.mcc, ndk_sys::ACONFIGURATION_MCC);
+        check(self.mnc != other.mnc, ndk_sys::ACONFIGURATION_MNC);
+        check(self.language != other.language || self.country != other.country, ndk_sys::ACONFIGURATION_LOCALE);
+        check(self.orientation != other.orientation, ndk_sys::ACONFIGURATION_ORIENTATION);
+        check(self.touchscreen != other.touchscreen, ndk_sys::ACONFIGURATION_TOUCHSCREEN);
+        check(self.density != other.density, ndk_sys::ACONFIGURATION_DENSITY);
+        check(self.keyboard != other.keyboard, ndk_sys::ACONFIGURATION_KEYBOARD);
+        check(self.navigation != other.navigation, ndk_sys::ACONFIGURATION_NAVIGATION);
+        check(self.keys_hidden != other.keys_hidden || self.nav_hidden != other.nav_hidden, ndk_sys::ACONFIGURATION_KEYBOARD_HIDDEN);
+        check(self.sdk_version != other.sdk_version, ndk_sys::ACONFIGURATION_VERSION);
+        check(self.screen_width_dp != other.screen_width_dp || self.screen_height_dp != other.screen_height_dp, ndk_sys::ACONFIGURATION_SCREEN_SIZE);
+        check(self.screen_size != other.screen_size || self.screen_long != other.screen_long, ndk_sys::ACONFIGURATION_SCREEN_LAYOUT);
+        check(self.screen_round != other.screen_round, ndk_sys::ACONFIGURATION_SCREEN_ROUND);
+        check(self.ui_mode_type != other.ui_mode_type || self.ui_mode_night != other.ui_mode_night, ndk_sys::ACONFIGURATION_UI_MODE);
+        check(self.smallest_screen_width_dp != other.smallest_screen_width_dp, ndk_sys::ACONFIGURATION_SMALLEST_SCREEN_SIZE);
+        check(self.layout_direction != other.layout_direction, ndk_sys::ACONFIGURATION_LAYOUTDIR);
+        diff
+    }
+}
+
+unsafe fn config<'a>(config: *mut AConfiguration) -> &'a TestConfiguration {
+    &*(config as *const TestConfiguration)
+}
+
+unsafe fn config_mut<'a>(config: *mut AConfiguration) -> &'a mut TestConfiguration {
+    &mut *(config as *mut TestConfiguration)
+}
+
+#[no_mangle]
+pub extern "C" fn AConfiguration_new() -> *mut AConfiguration {
+    Box::into_raw(Box::new(TestConfiguration::default())) as *mut AConfiguration
+}
+
+/// # Safety
+/// `config` must have been allocated with [`AConfiguration_new`]
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_delete(config: *mut AConfiguration) {
+    if let Some(config) = NonNull::new(config as *mut TestConfiguration) {
+        drop(Box::from_raw(config.as_ptr()));
+    }
+}
+
+/// There are no resources on the host, so this leaves `out` unchanged
+///
+/// # Safety
+/// `out` must be a valid configuration pointer
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_fromAssetManager(_out: *mut AConfiguration, _am: *mut ndk_sys::AAssetManager) {}
+
+/// # Safety
+/// `dest` and `src` must be valid configuration pointers
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_copy(dest: *mut AConfiguration, src: *mut AConfiguration) {
+    *config_mut(dest) = config(src).clone();
+}
+
+/// # Safety
+/// `config1` and `config2` must be valid configuration pointers
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_diff(config1: *mut AConfiguration, config2: *mut AConfiguration) -> i32 {
+    config(config1).diff(config(config2)) as i32
+}
+
+/// Resource matching isn't emulated: every configuration is considered a match
+///
+/// # Safety
+/// `base` and `requested` must be valid configuration pointers
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_match(_base: *mut AConfiguration, _requested: *mut AConfiguration) -> i32 {
+    1
+}
+
+/// # Safety
+/// `config` must be a valid configuration pointer and `out_language` must be
+/// valid for writing two chars
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_getLanguage(config_ptr: *mut AConfiguration, out_language: *mut c_char) {
+    let language = config(config_ptr).language;
+    *out_language = language[0] as c_char;
+    *out_language.add(1) = language[1] as c_char;
+}
+
+/// # Safety
+/// `config` must be a valid configuration pointer and `language` must be
+/// NULL or valid for reading two chars
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_setLanguage(config_ptr: *mut AConfiguration, language: *const c_char) {
+    config_mut(config_ptr).language = if language.is_null() { [0, 0] } else { [*language as u8, *language.add(1) as u8] };
+}
+
+/// # Safety
+/// `config` must be a valid configuration pointer and `out_country` must be
+/// valid for writing two chars
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_getCountry(config_ptr: *mut AConfiguration, out_country: *mut c_char) {
+    let country = config(config_ptr).country;
+    *out_country = country[0] as c_char;
+    *out_country.add(1) = country[1] as c_char;
+}
+
+/// # Safety
+/// `config` must be a valid configuration pointer and `country` must be
+/// NULL or valid for reading two chars
+#[no_mangle]
+pub unsafe extern "C" fn AConfiguration_setCountry(config_ptr: *mut AConfiguration, country: *const c_char) {
+    config_mut(config_ptr).country = if country.is_null() { [0, 0] } else { [*country as u8, *country.add(1) as u8] };
+}
+
+macro_rules! config_accessors {
+    ($($field:ident: $getter:ident, $setter:ident;)*) => {
+        $(
+            /// # Safety
+            /// `config` must be a valid configuration pointer
+            #[no_mangle]
+            pub unsafe extern "C" fn $getter(config_ptr: *mut AConfiguration) -> i32 {
+                config(config_ptr).$field
+            }
+
+            /// # Safety
+            /// `config` must be a valid configuration pointer
+            #[no_mangle]
+            pub unsafe extern "C" fn $setter(config_ptr: *mut AConfiguration, value: i32) {
+                config_mut(config_ptr).$field = value;
+            }
+        )*
+    };
+}
+
+config_accessors! {
+    mcc: AConfiguration_getMcc, AConfiguration_setMcc;
+    mnc: AConfiguration_getMnc, AConfiguration_setMnc;
+    orientation: AConfiguration_getOrientation, AConfiguration_setOrientation;
+    touchscreen: AConfiguration_getTouchscreen, AConfiguration_setTouchscreen;
+    density: AConfiguration_getDensity, AConfiguration_setDensity;
+    keyboard: AConfiguration_getKeyboard, AConfiguration_setKeyboard;
+    navigation: AConfiguration_getNavigation, AConfiguration_setNavigation;
+    keys_hidden: AConfiguration_getKeysHidden, AConfiguration_setKeysHidden;
+    nav_hidden: AConfiguration_getNavHidden, AConfiguration_setNavHidden;
+    sdk_version: AConfiguration_getSdkVersion, AConfiguration_setSdkVersion;
+    screen_size: AConfiguration_getScreenSize, AConfiguration_setScreenSize;
+    screen_long: AConfiguration_getScreenLong, AConfiguration_setScreenLong;
+    screen_round: AConfiguration_getScreenRound, AConfiguration_setScreenRound;
+    ui_mode_type: AConfiguration_getUiModeType, AConfiguration_setUiModeType;
+    ui_mode_night: AConfiguration_getUiModeNight, AConfiguration_setUiModeNight;
+    screen_width_dp: AConfiguration_getScreenWidthDp, AConfiguration_setScreenWidthDp;
+    screen_height_dp: AConfiguration_getScreenHeightDp, AConfiguration_setScreenHeightDp;
+    smallest_screen_width_dp: AConfiguration_getSmallestScreenWidthDp, AConfiguration_setSmallestScreenWidthDp;
+    layout_direction: AConfiguration_getLayoutDirection, AConfiguration_setLayoutDirection;
+}
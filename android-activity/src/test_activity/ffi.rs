@@ -0,0 +1,51 @@
+//! Rust equivalents of the `GameActivity` input structs
+//!
+//! The test backend reuses the `GameActivity` input API but without linking with
+//! the `GameActivity` library, so these mirror the bindgen generated definitions.
+
+#![allow(non_snake_case)]
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GameActivityPointerAxes {
+    pub id: i32,
+    pub axisValues: [f32; 48usize],
+    pub rawX: f32,
+    pub rawY: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GameActivityMotionEvent {
+    pub deviceId: i32,
+    pub source: i32,
+    pub action: i32,
+    pub eventTime: i64,
+    pub downTime: i64,
+    pub flags: i32,
+    pub metaState: i32,
+    pub actionButton: i32,
+    pub buttonState: i32,
+    pub classification: i32,
+    pub edgeFlags: i32,
+    pub pointerCount: u32,
+    pub pointers: [GameActivityPointerAxes; 8usize],
+    pub precisionX: f32,
+    pub precisionY: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GameActivityKeyEvent {
+    pub deviceId: i32,
+    pub source: i32,
+    pub action: i32,
+    pub eventTime: i64,
+    pub downTime: i64,
+    pub flags: i32,
+    pub metaState: i32,
+    pub modifiers: i32,
+    pub repeatCount: i32,
+    pub keyCode: i32,
+    pub scanCode: i32,
+}
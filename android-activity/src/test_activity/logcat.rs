@@ -0,0 +1,51 @@
+//! A host implementation of the NDK's `__android_log_write()`
+//!
+//! Messages are printed to stderr (in the same format as `adb logcat -v brief`)
+//! and are also kept in memory, so tests can check what an application logged
+//! via [`logcat_messages()`].
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::sync::Mutex;
+
+/// A message that was written to the emulated logcat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogcatMessage {
+    /// One of the `ndk_sys::android_LogPriority_ANDROID_LOG_*` values
+    pub priority: i32,
+    pub tag: String,
+    pub message: String,
+}
+
+static MESSAGES: Mutex<Vec<LogcatMessage>> = Mutex::new(Vec::new());
+
+/// Returns all of the messages that have been written to logcat by this process
+pub fn logcat_messages() -> Vec<LogcatMessage> {
+    MESSAGES.lock().unwrap().clone()
+}
+
+fn priority_char(priority: i32) -> char {
+    match priority as u32 {
+        ndk_sys::android_LogPriority_ANDROID_LOG_VERBOSE => 'V',
+        ndk_sys::android_LogPriority_ANDROID_LOG_DEBUG => 'D',
+        ndk_sys::android_LogPriority_ANDROID_LOG_INFO => 'I',
+        ndk_sys::android_LogPriority_ANDROID_LOG_WARN => 'W',
+        ndk_sys::android_LogPriority_ANDROID_LOG_ERROR => 'E',
+        ndk_sys::android_LogPriority_ANDROID_LOG_FATAL => 'F',
+        _ => '?',
+    }
+}
+
+/// # Safety
+/// `tag` and `text` must be valid, nul terminated strings
+#[no_mangle]
+pub unsafe extern "C" fn __android_log_write(prio: c_int, tag: *const c_char, text: *const c_char) -> c_int {
+    let message = LogcatMessage {
+        priority: prio,
+        tag: CStr::from_ptr(tag).to_string_lossy().into_owned(),
+        message: CStr::from_ptr(text).to_string_lossy().into_owned(),
+    };
+    eprintln!("{}/{}: {}", priority_char(prio), message.tag, message.message);
+    MESSAGES.lock().unwrap().push(message);
+    1
+}
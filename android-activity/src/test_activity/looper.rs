@@ -0,0 +1,376 @@
+//! A host implementation of the NDK's `ALooper` API, based on `poll(2)`
+//!
+//! This exports the same C symbols as `libandroid` so that code which is written
+//! against `ndk_sys::ALooper_*` (including the `ndk` crate and our own glue code)
+//! can run unmodified on a Linux host.
+//!
+//! The semantics follow the upstream `Looper` implementation: fds that were added
+//! without a callback are reported one at a time via their `ident`, callbacks are
+//! run internally (resulting in `ALOOPER_POLL_CALLBACK`) and `ALooper_wake` results
+//! in `ALOOPER_POLL_WAKE`.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::os::raw::{c_int, c_void};
+use std::os::unix::prelude::*;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ndk_sys::{ALooper, ALooper_callbackFunc};
+
+#[derive(Clone, Copy)]
+struct Request {
+    // Uniquely identifies this registration, in case the fd is re-registered
+    // while its callback is running
+    seq: u64,
+    ident: c_int,
+    events: c_int,
+    callback: ALooper_callbackFunc,
+    data: *mut c_void,
+}
+
+#[derive(Clone, Copy)]
+struct Response {
+    fd: RawFd,
+    ident: c_int,
+    events: c_int,
+    data: *mut c_void,
+}
+
+#[derive(Default)]
+struct LooperState {
+    // A BTreeMap ensures fds are polled (and reported) in a deterministic order
+    requests: BTreeMap<RawFd, Request>,
+    responses: VecDeque<Response>,
+    wake_requested: bool,
+    next_seq: u64,
+}
+
+// The raw `data` pointers are opaque to the looper and just handed back to
+// whichever thread polls the looper, same as with the real ALooper.
+unsafe impl Send for LooperState {}
+
+pub(crate) struct Looper {
+    state: Mutex<LooperState>,
+    wake_read: RawFd,
+    wake_write: RawFd,
+}
+
+thread_local! {
+    static THREAD_LOOPER: RefCell<Option<Arc<Looper>>> = const { RefCell::new(None) };
+}
+
+fn poll_events_from_looper_events(events: c_int) -> libc::c_short {
+    let mut poll_events = 0;
+    if events & ndk_sys::ALOOPER_EVENT_INPUT as c_int != 0 {
+        poll_events |= libc::POLLIN;
+    }
+    if events & ndk_sys::ALOOPER_EVENT_OUTPUT as c_int != 0 {
+        poll_events |= libc::POLLOUT;
+    }
+    poll_events
+}
+
+fn looper_events_from_poll_events(revents: libc::c_short) -> c_int {
+    let mut events = 0;
+    if revents & libc::POLLIN != 0 {
+        events |= ndk_sys::ALOOPER_EVENT_INPUT;
+    }
+    if revents & libc::POLLOUT != 0 {
+        events |= ndk_sys::ALOOPER_EVENT_OUTPUT;
+    }
+    if revents & libc::POLLERR != 0 {
+        events |= ndk_sys::ALOOPER_EVENT_ERROR;
+    }
+    if revents & libc::POLLHUP != 0 {
+        events |= ndk_sys::ALOOPER_EVENT_HANGUP;
+    }
+    if revents & libc::POLLNVAL != 0 {
+        events |= ndk_sys::ALOOPER_EVENT_INVALID;
+    }
+    events as c_int
+}
+
+impl Looper {
+    fn new() -> Self {
+        let mut fds: [RawFd; 2] = Default::default();
+        unsafe {
+            if libc::pipe2(fds.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) != 0 {
+                panic!("Failed to create looper wake pipe");
+            }
+        }
+        Self {
+            state: Mutex::new(Default::default()),
+            wake_read: fds[0],
+            wake_write: fds[1],
+        }
+    }
+
+    fn as_ptr(self: &Arc<Self>) -> *mut ALooper {
+        Arc::as_ptr(self) as *mut ALooper
+    }
+
+    /// # Safety
+    /// `ptr` must have been returned by [`ALooper_prepare`] or [`ALooper_forThread`]
+    /// and still be referenced
+    unsafe fn from_ptr<'a>(ptr: *mut ALooper) -> &'a Looper {
+        &*(ptr as *const Looper)
+    }
+
+    fn signal(&self) {
+        let byte = 1u8;
+        unsafe {
+            libc::write(self.wake_write, &byte as *const u8 as *const c_void, 1);
+        }
+    }
+
+    fn drain(&self) {
+        let mut buf = [0u8; 16];
+        unsafe {
+            while libc::read(self.wake_read, buf.as_mut_ptr() as *mut c_void, buf.len()) > 0 {}
+        }
+    }
+
+    fn wake(&self) {
+        self.state.lock().unwrap().wake_requested = true;
+        self.signal();
+    }
+
+    fn add_fd(&self, fd: RawFd, ident: c_int, events: c_int, callback: ALooper_callbackFunc, data: *mut c_void) -> c_int {
+        let ident = if callback.is_some() { ndk_sys::ALOOPER_POLL_CALLBACK } else { ident };
+        if ident < 0 && callback.is_none() {
+            return -1;
+        }
+        let mut state = self.state.lock().unwrap();
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.requests.insert(fd, Request { seq, ident, events, callback, data });
+
+        // Make sure that a concurrent poll() picks up the new fd
+        self.signal();
+        1
+    }
+
+    fn remove_fd(&self, fd: RawFd) -> c_int {
+        let mut state = self.state.lock().unwrap();
+        let removed = state.requests.remove(&fd).is_some();
+        state.responses.retain(|response| response.fd != fd);
+        if removed {
+            self.signal();
+            1
+        } else {
+            0
+        }
+    }
+
+    unsafe fn poll_once(&self, timeout: Option<Duration>, out_fd: *mut c_int, out_events: *mut c_int, out_data: *mut *mut c_void) -> c_int {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        loop {
+            let mut pollfds = vec![libc::pollfd { fd: self.wake_read, events: libc::POLLIN, revents: 0 }];
+            {
+                let mut state = self.state.lock().unwrap();
+                if let Some(response) = state.responses.pop_front() {
+                    if !out_fd.is_null() {
+                        *out_fd = response.fd;
+                    }
+                    if !out_events.is_null() {
+                        *out_events = response.events;
+                    }
+                    if !out_data.is_null() {
+                        *out_data = response.data;
+                    }
+                    return response.ident;
+                }
+                for (fd, request) in state.requests.iter() {
+                    pollfds.push(libc::pollfd { fd: *fd, events: poll_events_from_looper_events(request.events), revents: 0 });
+                }
+            }
+
+            let timeout_millis = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline { 0 } else { (deadline - now).as_millis().max(1) as c_int }
+                }
+                None => -1
+            };
+
+            let n = libc::poll(pollfds.as_mut_ptr(), pollfds.len() as libc::nfds_t, timeout_millis);
+            if n < 0 {
+                if std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return ndk_sys::ALOOPER_POLL_ERROR;
+            }
+            if n == 0 {
+                return ndk_sys::ALOOPER_POLL_TIMEOUT;
+            }
+
+            let mut result = None;
+            let mut callbacks = vec![];
+            {
+                let mut state = self.state.lock().unwrap();
+                for pollfd in pollfds.iter().filter(|pollfd| pollfd.revents != 0) {
+                    if pollfd.fd == self.wake_read {
+                        self.drain();
+                        if state.wake_requested {
+                            state.wake_requested = false;
+                            result = Some(ndk_sys::ALOOPER_POLL_WAKE);
+                        }
+                        continue;
+                    }
+                    // The fd may have been removed while we were polling
+                    if let Some(request) = state.requests.get(&pollfd.fd).copied() {
+                        let events = looper_events_from_poll_events(pollfd.revents);
+                        if request.callback.is_some() {
+                            callbacks.push((pollfd.fd, events, request));
+                        } else {
+                            state.responses.push_back(Response { fd: pollfd.fd, ident: request.ident, events, data: request.data });
+                        }
+                    }
+                }
+            }
+
+            // Callbacks are invoked without holding the lock so that they can
+            // add or remove fds
+            for (fd, events, request) in callbacks.iter() {
+                let callback = request.callback.unwrap();
+                if callback(*fd, *events, request.data) == 0 {
+                    let mut state = self.state.lock().unwrap();
+                    if let Some(current) = state.requests.get(fd) {
+                        if current.seq == request.seq {
+                            state.requests.remove(fd);
+                        }
+                    }
+                }
+            }
+            if !callbacks.is_empty() {
+                result = Some(ndk_sys::ALOOPER_POLL_CALLBACK);
+            }
+
+            if let Some(result) = result {
+                return result;
+            }
+        }
+    }
+}
+
+impl Drop for Looper {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.wake_read);
+            libc::close(self.wake_write);
+        }
+    }
+}
+
+fn timeout_from_millis(timeout_millis: c_int) -> Option<Duration> {
+    if timeout_millis < 0 {
+        None
+    } else {
+        Some(Duration::from_millis(timeout_millis as u64))
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ALooper_forThread() -> *mut ALooper {
+    THREAD_LOOPER.with(|looper| {
+        looper.borrow().as_ref().map(|looper| looper.as_ptr()).unwrap_or(ptr::null_mut())
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ALooper_prepare(_opts: c_int) -> *mut ALooper {
+    THREAD_LOOPER.with(|looper| {
+        looper.borrow_mut().get_or_insert_with(|| Arc::new(Looper::new())).as_ptr()
+    })
+}
+
+/// # Safety
+/// `looper` must be a valid looper pointer
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_acquire(looper: *mut ALooper) {
+    Arc::increment_strong_count(looper as *const Looper);
+}
+
+/// # Safety
+/// `looper` must be a valid looper pointer that was previously acquired
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_release(looper: *mut ALooper) {
+    Arc::decrement_strong_count(looper as *const Looper);
+}
+
+/// # Safety
+/// The out pointers must be NULL or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_pollOnce(
+    timeout_millis: c_int,
+    out_fd: *mut c_int,
+    out_events: *mut c_int,
+    out_data: *mut *mut c_void,
+) -> c_int {
+    let looper = ALooper_forThread();
+    if looper.is_null() {
+        return ndk_sys::ALOOPER_POLL_ERROR;
+    }
+    Looper::from_ptr(looper).poll_once(timeout_from_millis(timeout_millis), out_fd, out_events, out_data)
+}
+
+/// # Safety
+/// The out pointers must be NULL or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_pollAll(
+    timeout_millis: c_int,
+    out_fd: *mut c_int,
+    out_events: *mut c_int,
+    out_data: *mut *mut c_void,
+) -> c_int {
+    let looper = ALooper_forThread();
+    if looper.is_null() {
+        return ndk_sys::ALOOPER_POLL_ERROR;
+    }
+    let looper = Looper::from_ptr(looper);
+
+    let deadline = timeout_from_millis(timeout_millis).map(|timeout| Instant::now() + timeout);
+    loop {
+        let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        let result = looper.poll_once(remaining, out_fd, out_events, out_data);
+        if result != ndk_sys::ALOOPER_POLL_CALLBACK {
+            return result;
+        }
+        if let Some(deadline) = deadline {
+            if Instant::now() >= deadline {
+                return ndk_sys::ALOOPER_POLL_TIMEOUT;
+            }
+        }
+    }
+}
+
+/// # Safety
+/// `looper` must be a valid looper pointer
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_wake(looper: *mut ALooper) {
+    Looper::from_ptr(looper).wake();
+}
+
+/// # Safety
+/// `looper` must be a valid looper pointer
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_addFd(
+    looper: *mut ALooper,
+    fd: c_int,
+    ident: c_int,
+    events: c_int,
+    callback: ALooper_callbackFunc,
+    data: *mut c_void,
+) -> c_int {
+    Looper::from_ptr(looper).add_fd(fd, ident, events, callback, data)
+}
+
+/// # Safety
+/// `looper` must be a valid looper pointer
+#[no_mangle]
+pub unsafe extern "C" fn ALooper_removeFd(looper: *mut ALooper, fd: c_int) -> c_int {
+    Looper::from_ptr(looper).remove_fd(fd)
+}
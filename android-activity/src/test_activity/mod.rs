@@ -0,0 +1,758 @@
+//! A host-only backend for testing applications with a plain `cargo test`
+//!
+//! Instead of being driven by a Java `Activity`, this backend is driven by a
+//! [`TestActivity`] that plays the role of the Java main thread: it spawns the
+//! application's main function on a dedicated thread (just like
+//! `android_native_app_glue` does) and then lets a test script lifecycle
+//! transitions, window changes, configuration changes and input.
+//!
+//! The [`TestActivity`] methods drive the same glue layer that the
+//! `native-activity` backend uses, following the same handshake as the
+//! corresponding `ANativeActivity` callback, so for example
+//! [`TestActivity::pause()`] won't return until the application has started
+//! handling the [`MainEvent::Pause`] event, and
+//! [`TestActivity::save_instance_state()`] blocks until the application has
+//! finished handling [`MainEvent::SaveState`].
+//!
+//! The handful of NDK APIs that the glue layer depends on (`ALooper`,
+//! `ANativeWindow`, `AConfiguration`, `AAssetManager` and logging) are emulated, which
+//! means the usual `ndk` types can be used within tests. The emulated
+//! `AAssetManager` is always empty, but [`AndroidApp::assets()`] serves the
+//! application's assets from a local directory (see
+//! [`TestActivity::set_assets_dir()`]).
+//!
+//! The application's data directories are emulated within a per-process
+//! temporary directory, and [`AndroidApp::sdk_version()`] is the
+//! [`TestConfiguration::sdk_version`] that the activity was created with.
+//!
+//! Input is buffered like with `GameActivity`, but an input queue can also be
+//! emulated (see [`TestActivity::input_queue_created()`]) so that new input
+//! wakes up the application like with `NativeActivity`.
+
+use ndk::asset::AssetManager;
+use ndk::configuration::Configuration;
+use ndk_sys::{ALooper, ANativeWindow};
+use std::cell::{Cell, RefCell};
+use std::os::unix::prelude::*;
+use std::path::PathBuf;
+use std::ptr::{self, NonNull};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::{AndroidApp, AndroidAppWaker, Assets, InsetType, Rect, Size, TrimMemoryLevel, WindowInsets};
+use crate::fd_sources::FdSources;
+use crate::finish::Finisher;
+use crate::poll::{AppCmd, GlueBackend, PollState};
+use crate::state_spill::StateSpill;
+use crate::lifecycle::Lifecycle;
+use crate::recording::RecordingState;
+
+use self::ffi::{GameActivityKeyEvent, GameActivityMotionEvent, GameActivityPointerAxes};
+use self::input::{InputEvent, KeyAction, Keycode, MotionAction, Source};
+use self::window::FakeNativeWindow;
+
+mod asset;
+mod choreographer;
+mod config;
+mod logcat;
+mod looper;
+mod window;
+
+pub use config::TestConfiguration;
+pub use logcat::{logcat_messages, LogcatMessage};
+
+pub(crate) mod ffi;
+
+// The test backend uses the same input API as `GameActivity` since the plain
+// structs used to represent events can easily be synthesized by tests
+#[path = "../game_activity/input.rs"]
+pub mod input;
+
+#[path = "../game_activity/input_buffer.rs"]
+mod input_buffer;
+
+use self::input_buffer::InputBuffer;
+
+/// `test` specific extensions to [`AndroidApp`], matching those for `GameActivity` input
+pub trait AndroidAppExt {
+    /// Sets the maximum number of input events that may be buffered between
+    /// calls to [`AndroidApp::input_events()`]
+    ///
+    /// The input buffer grows as needed up to this limit (1024 events by
+    /// default), after which new events are dropped and counted in the value
+    /// returned by the next call to [`AndroidApp::input_events()`].
+    fn set_max_buffered_input_events(&self, max_events: usize);
+}
+
+impl AndroidAppExt for AndroidApp {
+    fn set_max_buffered_input_events(&self, max_events: usize) {
+        self.inner.input_buffer.set_max_events(max_events);
+    }
+}
+
+// The same glue layer as the `native-activity` backend, which means the
+// handshake with the Java main thread is exercised by host tests. The glue's
+// input queue pointer is only a placeholder for the emulated queue's eventfd.
+#[allow(dead_code)]
+#[path = "../native_activity/glue.rs"]
+mod glue;
+
+use glue::*;
+
+const APP_CMD_WINDOW_INSETS_CHANGED: i8 = AppCmd::WindowInsetsChanged as i8;
+
+// By default the emulated application's data directories are all within a
+// per-process temporary directory, which (unlike on a device) aren't created up
+// front
+fn default_data_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("android-activity-{}", std::process::id()))
+}
+
+// State that's delivered by the emulated Java side without a glue handshake
+#[derive(Debug, Default)]
+struct HostState {
+    config: TestConfiguration,
+    input_buffer: Arc<InputBuffer>,
+    insets: WindowInsets,
+
+    // The directory that `AndroidApp::assets()` serves, if not `assets`
+    assets_dir: Option<PathBuf>,
+
+    // The directory that contains the application's `files`, `external` and
+    // `obb` directories
+    data_dir: PathBuf,
+
+    // The `TRIM_MEMORY_*` level for the last `APP_CMD_LOW_MEMORY`
+    trim_memory_level: i32,
+
+    // An eventfd that's signalled whenever input is buffered, which stands in
+    // for the fd of an `AInputQueue` between `input_queue_created()` and
+    // `input_queue_destroyed()`
+    input_queue: Option<OwnedFd>,
+}
+
+impl HostState {
+    fn signal_input_queue(&self) {
+        if let Some(fd) = &self.input_queue {
+            let count: u64 = 1;
+            unsafe {
+                libc::write(fd.as_raw_fd(), &count as *const u64 as *const libc::c_void, std::mem::size_of::<u64>());
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct StateSaver<'a> {
+    pub(crate) app: &'a AndroidAppInner,
+}
+
+impl<'a> StateSaver<'a> {
+    pub fn store(&self, state: &[u8]) {
+        self.app.glue.lock().saved_state = Some(state.to_vec());
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct StateLoader<'a> {
+    pub(crate) app: &'a AndroidAppInner,
+}
+
+impl<'a> StateLoader<'a> {
+    pub fn load(&self) -> Option<Vec<u8>> {
+        self.app.glue.lock().saved_state.clone().filter(|state| !state.is_empty())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct AndroidAppInner {
+    glue: Arc<NativeAppGlue>,
+    host: Arc<Mutex<HostState>>,
+    input_buffer: Arc<InputBuffer>,
+    looper: NonNull<ALooper>,
+    poll: PollState,
+
+    // Whether the emulated input queue is attached to the looper, which is
+    // paused from when new input is reported until the next `input_events()`
+    input_attached: Cell<bool>,
+
+    lifecycle: Lifecycle,
+    recording: RefCell<RecordingState>,
+    state_spill: StateSpill,
+    finisher: Arc<Finisher>,
+}
+
+impl AndroidAppInner {
+    fn from_glue(glue: Arc<NativeAppGlue>, host: Arc<Mutex<HostState>>, finisher: Arc<Finisher>, looper: NonNull<ALooper>) -> AndroidApp {
+        let (config, input_buffer, data_dir) = {
+            let host = host.lock().unwrap();
+            (host.config.to_configuration(), host.input_buffer.clone(), host.data_dir.clone())
+        };
+
+        // The looper is owned by the thread that prepared it, so we take our own
+        // reference to make sure it outlives any `AndroidApp` clones
+        unsafe { ndk_sys::ALooper_acquire(looper.as_ptr()) };
+
+        let state_spill = StateSpill::new(Some(data_dir.join("files")), glue.lock().saved_state.as_deref());
+        AndroidApp {
+            inner: Rc::new(AndroidAppInner {
+                glue,
+                host,
+                input_buffer,
+                looper,
+                poll: PollState::new(config, unsafe { FdSources::new(looper) }),
+                input_attached: Cell::new(false),
+                lifecycle: Lifecycle::default(),
+                recording: RefCell::new(RecordingState::default()),
+                state_spill,
+                finisher,
+            })
+        }
+    }
+
+    pub fn create_waker(&self) -> AndroidAppWaker {
+        AndroidAppWaker { looper: self.looper }
+    }
+
+    pub(crate) fn lifecycle(&self) -> &Lifecycle {
+        &self.lifecycle
+    }
+
+    pub(crate) fn recording(&self) -> &RefCell<RecordingState> {
+        &self.recording
+    }
+
+    pub(crate) fn state_spill(&self) -> &StateSpill {
+        &self.state_spill
+    }
+
+    pub fn content_rect(&self) -> Rect {
+        self.glue.lock().content_rect.clone()
+    }
+
+    pub fn asset_manager(&self) -> AssetManager {
+        unsafe { AssetManager::from_ptr(asset::asset_manager_ptr()) }
+    }
+
+    pub(crate) fn finisher(&self) -> &Arc<Finisher> {
+        &self.finisher
+    }
+
+    pub fn assets(&self) -> Assets {
+        let host = self.host.lock().unwrap();
+        Assets::from_dir(host.assets_dir.clone().unwrap_or_else(|| PathBuf::from("assets")))
+    }
+
+    pub fn internal_data_path(&self) -> Option<PathBuf> {
+        Some(self.host.lock().unwrap().data_dir.join("files"))
+    }
+
+    pub fn external_data_path(&self) -> Option<PathBuf> {
+        Some(self.host.lock().unwrap().data_dir.join("external"))
+    }
+
+    pub fn obb_path(&self) -> Option<PathBuf> {
+        Some(self.host.lock().unwrap().data_dir.join("obb"))
+    }
+
+    pub fn sdk_version(&self) -> u32 {
+        self.host.lock().unwrap().config.sdk_version as u32
+    }
+
+    pub fn input_events<F>(&self, mut callback: F) -> usize
+        where F: FnMut(&InputEvent)
+    {
+        // Any input that was reported before the queue is re-attached will be
+        // read below, so the eventfd is reset first
+        if !self.input_attached.get() && self.glue.lock().input_queue.is_some() {
+            if let Some(fd) = &self.host.lock().unwrap().input_queue {
+                let mut count: u64 = 0;
+                unsafe {
+                    libc::read(fd.as_raw_fd(), &mut count as *mut u64 as *mut libc::c_void, std::mem::size_of::<u64>());
+                }
+                self.attach_input_queue(fd.as_raw_fd());
+            }
+        }
+
+        let events = self.input_buffer.take();
+
+        for event in &events.events {
+            callback(event);
+        }
+
+        events.overflowed
+    }
+
+    fn attach_input_queue(&self, fd: RawFd) {
+        unsafe {
+            ndk_sys::ALooper_addFd(self.looper.as_ptr(), fd, LOOPER_ID_INPUT, ndk_sys::ALOOPER_EVENT_INPUT as _, None, ptr::null_mut());
+        }
+        self.input_attached.set(true);
+    }
+
+    fn detach_input_queue(&self, fd: RawFd) {
+        if self.input_attached.replace(false) {
+            unsafe { ndk_sys::ALooper_removeFd(self.looper.as_ptr(), fd) };
+        }
+    }
+}
+
+impl GlueBackend for AndroidAppInner {
+    fn poll_state(&self) -> &PollState {
+        &self.poll
+    }
+
+    fn cmd_fd(&self) -> RawFd {
+        self.glue.msgread()
+    }
+
+    fn is_glue_fd(&self, fd: RawFd) -> bool {
+        let input_queue = &self.host.lock().unwrap().input_queue;
+        fd == self.cmd_fd() || input_queue.as_ref().is_some_and(|queue| queue.as_raw_fd() == fd)
+    }
+
+    fn read_cmd(&self) -> Option<i8> {
+        let cmd_i = self.glue.read_cmd();
+        (cmd_i >= 0).then_some(cmd_i)
+    }
+
+    fn pre_exec_cmd(&self, cmd_i: i8) {
+        if cmd_i != APP_CMD_INPUT_CHANGED {
+            self.glue.pre_exec_cmd(cmd_i);
+            return;
+        }
+
+        // As with a real input queue, the eventfd is closed as soon as the glue
+        // has seen that it's been removed, so it must be detached first
+        let had_queue = self.glue.lock().input_queue.is_some();
+        if had_queue {
+            if let Some(fd) = &self.host.lock().unwrap().input_queue {
+                self.detach_input_queue(fd.as_raw_fd());
+            }
+        }
+        self.glue.pre_exec_cmd(cmd_i);
+        let has_queue = self.glue.lock().input_queue.is_some();
+        if has_queue {
+            if let Some(fd) = &self.host.lock().unwrap().input_queue {
+                self.attach_input_queue(fd.as_raw_fd());
+            }
+        }
+    }
+
+    fn post_exec_cmd(&self, cmd_i: i8) {
+        self.glue.post_exec_cmd(cmd_i);
+    }
+
+    fn new_window(&self) -> Option<(NonNull<ANativeWindow>, Size)> {
+        let state = self.glue.lock();
+        state.window.map(|window| (window, state.window_size))
+    }
+
+    fn resized_window_size(&self) -> Size {
+        self.glue.lock().window_size
+    }
+
+    fn changed_content_rect(&self) -> Rect {
+        self.glue.lock().content_rect.clone()
+    }
+
+    fn changed_config(&self) -> Configuration {
+        self.host.lock().unwrap().config.to_configuration()
+    }
+
+    fn low_memory_level(&self) -> TrimMemoryLevel {
+        TrimMemoryLevel::from_raw(self.host.lock().unwrap().trim_memory_level)
+    }
+
+    fn input_available(&self) {
+        if let Some(fd) = &self.host.lock().unwrap().input_queue {
+            self.detach_input_queue(fd.as_raw_fd());
+        }
+    }
+
+    fn changed_insets(&self) -> WindowInsets {
+        self.host.lock().unwrap().insets.clone()
+    }
+
+    fn state_loader(&self) -> crate::StateLoader<'_> {
+        crate::StateLoader::new(StateLoader { app: self })
+    }
+
+    fn state_saver(&self) -> crate::StateSaver<'_> {
+        crate::StateSaver::new(StateSaver { app: self })
+    }
+}
+
+impl Drop for AndroidAppInner {
+    fn drop(&mut self) {
+        unsafe { ndk_sys::ALooper_release(self.looper.as_ptr()) };
+    }
+}
+
+// The equivalent of `android_app_entry`, run on the application's main thread
+fn app_entry<F>(glue: Arc<NativeAppGlue>, host: Arc<Mutex<HostState>>, finisher: Arc<Finisher>, main: F)
+    where F: FnOnce(AndroidApp)
+{
+    let _guard = DestroyGuard(glue.clone());
+
+    let app = unsafe {
+        let looper = ndk_sys::ALooper_prepare(ndk_sys::ALOOPER_PREPARE_ALLOW_NON_CALLBACKS as _);
+        ndk_sys::ALooper_addFd(looper, glue.msgread(), LOOPER_ID_MAIN, ndk_sys::ALOOPER_EVENT_INPUT as _, None, ptr::null_mut());
+        AndroidAppInner::from_glue(glue.clone(), host, finisher, NonNull::new(looper).unwrap())
+    };
+
+    glue.set_running();
+
+    #[cfg(feature = "logger")]
+    crate::logger::init();
+
+    crate::run_main_with_app(app, main);
+}
+
+// There's no Java `Activity` to finish, so the request is only recorded, for
+// `TestActivity::is_finishing()`
+unsafe fn finish_activity(_activity: NonNull<std::os::raw::c_void>) {}
+
+fn monotonic_time_nanos() -> i64 {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) };
+    ts.tv_sec * 1_000_000_000 + ts.tv_nsec
+}
+
+/// Emulates the Java `Activity` and main thread for an application under test
+///
+/// Dropping a `TestActivity` is equivalent to calling [`TestActivity::destroy()`]
+#[derive(Debug)]
+pub struct TestActivity {
+    glue: Arc<NativeAppGlue>,
+    host: Arc<Mutex<HostState>>,
+    finisher: Arc<Finisher>,
+
+    // The surface that has been created on the Java side, if any
+    window: Mutex<Option<&'static FakeNativeWindow>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TestActivity {
+    /// Creates a new activity and runs `main` on a dedicated application thread,
+    /// as if it were the application's `android_main` function
+    ///
+    /// As with `android_main`, the app can also be queried via
+    /// [`android_app()`](crate::android_app) while `main` is running.
+    ///
+    /// `saved_state` is the state that was previously returned by
+    /// [`TestActivity::save_instance_state()`] if the activity is being re-created.
+    ///
+    /// This doesn't return until the application thread is running.
+    pub fn create<F>(saved_state: Option<&[u8]>, main: F) -> Self
+        where F: FnOnce(AndroidApp) + Send + 'static
+    {
+        Self::create_with_config(saved_state, TestConfiguration::default(), main)
+    }
+
+    /// Like [`TestActivity::create()`] but with an initial device configuration
+    pub fn create_with_config<F>(saved_state: Option<&[u8]>, config: TestConfiguration, main: F) -> Self
+        where F: FnOnce(AndroidApp) + Send + 'static
+    {
+        Self::create_with_host(saved_state, HostState { config, data_dir: default_data_dir(), ..Default::default() }, main)
+    }
+
+    /// Like [`TestActivity::create()`] but with the application's data
+    /// directories (such as [`AndroidApp::internal_data_path()`]) within
+    /// `data_dir`, instead of a temporary directory that's shared by every
+    /// activity in the process
+    pub fn create_with_data_dir<F>(saved_state: Option<&[u8]>, data_dir: impl Into<PathBuf>, main: F) -> Self
+        where F: FnOnce(AndroidApp) + Send + 'static
+    {
+        Self::create_with_host(saved_state, HostState { data_dir: data_dir.into(), ..Default::default() }, main)
+    }
+
+    fn create_with_host<F>(saved_state: Option<&[u8]>, host: HostState, main: F) -> Self
+        where F: FnOnce(AndroidApp) + Send + 'static
+    {
+        let glue = Arc::new(NativeAppGlue::new(saved_state));
+        let host = Arc::new(Mutex::new(host));
+        let finisher = unsafe { Finisher::new(NonNull::dangling(), finish_activity) };
+
+        let thread = {
+            let glue = glue.clone();
+            let host = host.clone();
+            let finisher = finisher.clone();
+            thread::Builder::new()
+                .name("android_main".to_string())
+                .spawn(move || app_entry(glue, host, finisher, main))
+                .expect("Failed to spawn application thread")
+        };
+
+        glue.wait_until_running();
+
+        Self { glue, host, finisher, window: Mutex::new(None), thread: Some(thread) }
+    }
+
+    /// Emulates `Activity.onStart()`, waiting until the application starts handling [`MainEvent::Start`]
+    pub fn start(&self) {
+        self.glue.set_activity_state(APP_CMD_START);
+    }
+
+    /// Emulates `Activity.onResume()`, waiting until the application starts handling [`MainEvent::Resume`]
+    pub fn resume(&self) {
+        self.glue.set_activity_state(APP_CMD_RESUME);
+    }
+
+    /// Emulates `Activity.onPause()`, waiting until the application starts handling [`MainEvent::Pause`]
+    pub fn pause(&self) {
+        self.glue.set_activity_state(APP_CMD_PAUSE);
+    }
+
+    /// Emulates `Activity.onStop()`, waiting until the application starts handling [`MainEvent::Stop`]
+    pub fn stop(&self) {
+        self.glue.set_activity_state(APP_CMD_STOP);
+    }
+
+    /// Emulates `Activity.onSaveInstanceState()`, waiting until the application has
+    /// handled [`MainEvent::SaveState`] and returning whatever state it stored
+    pub fn save_instance_state(&self) -> Option<Vec<u8>> {
+        self.glue.save_instance_state()
+    }
+
+    fn set_window(&self, window: Option<&'static FakeNativeWindow>) {
+        let mut current = self.window.lock().unwrap();
+        if let Some(old_window) = current.take() {
+            // Once the application has finished handling `TerminateWindow`
+            // any further use of the window will fail
+            self.glue.set_window(None);
+            old_window.destroy();
+        }
+        if let Some(window) = window {
+            self.glue.set_window(NonNull::new(window.as_ptr()));
+        }
+        *current = window;
+    }
+
+    /// Emulates the creation of a new surface with the given size, waiting until
+    /// the application starts handling [`MainEvent::InitWindow`]
+    ///
+    /// If there is an existing window then it is destroyed first.
+    pub fn native_window_created(&self, width: i32, height: i32) {
+        self.set_window(Some(FakeNativeWindow::new(width, height)));
+    }
+
+    /// Emulates the current surface being replaced by a new one with the given
+    /// size, in a single `onNativeWindowCreated` callback
+    ///
+    /// The [`MainEvent::TerminateWindow`] and [`MainEvent::InitWindow`] commands
+    /// are sent together, and this waits until the application starts handling
+    /// [`MainEvent::InitWindow`]. If there's no existing window this is the same
+    /// as [`TestActivity::native_window_created()`].
+    pub fn native_window_replaced(&self, width: i32, height: i32) {
+        let window = FakeNativeWindow::new(width, height);
+        let mut current = self.window.lock().unwrap();
+        self.glue.set_window(NonNull::new(window.as_ptr()));
+        if let Some(old_window) = current.replace(window) {
+            old_window.destroy();
+        }
+    }
+
+    /// Emulates the destruction of the current surface, waiting until the
+    /// application has finished handling [`MainEvent::TerminateWindow`]
+    ///
+    /// Using the [`NativeWindow`] after this point will fail.
+    pub fn native_window_destroyed(&self) {
+        self.set_window(None);
+    }
+
+    /// Resizes the current window and notifies the application with [`MainEvent::WindowResized`]
+    pub fn native_window_resized(&self, width: i32, height: i32) {
+        if let Some(window) = *self.window.lock().unwrap() {
+            window.resize(width, height);
+            self.glue.set_window_size(Size { width, height });
+        }
+    }
+
+    /// Notifies the application with [`MainEvent::RedrawNeeded`]
+    pub fn native_window_redraw_needed(&self) {
+        self.glue.write_cmd(APP_CMD_WINDOW_REDRAW_NEEDED);
+    }
+
+    /// Updates the content rect and notifies the application with [`MainEvent::ContentRectChanged`]
+    pub fn content_rect_changed(&self, rect: Rect) {
+        self.glue.set_content_rect(rect);
+    }
+
+    /// Notifies the application with [`MainEvent::GainedFocus`] or [`MainEvent::LostFocus`]
+    pub fn window_focus_changed(&self, has_focus: bool) {
+        self.glue.write_cmd(if has_focus { APP_CMD_GAINED_FOCUS } else { APP_CMD_LOST_FOCUS });
+    }
+
+    /// Sets the local directory that's served by [`AndroidApp::assets()`]
+    ///
+    /// By default this is the `assets` directory relative to the current
+    /// directory, which for `cargo test` is the root of the package being tested.
+    pub fn set_assets_dir(&self, dir: impl Into<PathBuf>) {
+        self.host.lock().unwrap().assets_dir = Some(dir.into());
+    }
+
+    /// Modifies the device configuration and notifies the application with [`MainEvent::ConfigChanged`]
+    pub fn configuration_changed<F>(&self, update: F)
+        where F: FnOnce(&mut TestConfiguration)
+    {
+        update(&mut self.host.lock().unwrap().config);
+        self.glue.write_cmd(APP_CMD_CONFIG_CHANGED);
+    }
+
+    /// Notifies the application with [`MainEvent::LowMemory`] at [`TrimMemoryLevel::Complete`],
+    /// like `NativeActivity`'s `onLowMemory` callback
+    pub fn low_memory(&self) {
+        self.trim_memory(TrimMemoryLevel::Complete);
+    }
+
+    /// Notifies the application with [`MainEvent::LowMemory`] at the given level,
+    /// like `GameActivity`'s `onTrimMemory` callback
+    pub fn trim_memory(&self, level: TrimMemoryLevel) {
+        self.host.lock().unwrap().trim_memory_level = level.to_raw();
+        self.glue.write_cmd(APP_CMD_LOW_MEMORY);
+    }
+
+    /// Updates the window insets and notifies the application with [`MainEvent::InsetsChanged`]
+    ///
+    /// Any type of inset that isn't given is zero.
+    pub fn window_insets_changed(&self, insets: &[(InsetType, Rect)]) {
+        let mut window_insets = WindowInsets::default();
+        for (inset_type, rect) in insets {
+            window_insets.set(*inset_type, rect.clone());
+        }
+        self.host.lock().unwrap().insets = window_insets;
+        self.glue.write_cmd(APP_CMD_WINDOW_INSETS_CHANGED);
+    }
+
+    /// Emulates `onInputQueueCreated`, waiting until the application starts
+    /// handling the resulting `APP_CMD_INPUT_CHANGED` command
+    ///
+    /// Until [`TestActivity::input_queue_destroyed()`], new input wakes up
+    /// [`AndroidApp::poll_events()`] like with `NativeActivity`.
+    pub fn input_queue_created(&self) {
+        let fd = unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK) };
+        assert!(fd >= 0, "Failed to create eventfd: {}", std::io::Error::last_os_error());
+        {
+            let mut host = self.host.lock().unwrap();
+            assert!(host.input_queue.is_none(), "An input queue has already been created");
+            host.input_queue = Some(unsafe { OwnedFd::from_raw_fd(fd) });
+        }
+        self.glue.set_input_queue(Some(NonNull::dangling()));
+    }
+
+    /// Emulates `onInputQueueDestroyed`, waiting until the application starts
+    /// handling the resulting `APP_CMD_INPUT_CHANGED` command
+    pub fn input_queue_destroyed(&self) {
+        self.glue.set_input_queue(None);
+        self.host.lock().unwrap().input_queue = None;
+    }
+
+    /// Buffers a keyboard event that will be returned by [`AndroidApp::input_events()`]
+    ///
+    /// As with `GameActivity`, buffering input doesn't wake up the application
+    /// unless an input queue has been created.
+    pub fn key_event(&self, action: KeyAction, key_code: Keycode) {
+        let now = monotonic_time_nanos();
+        let event = GameActivityKeyEvent {
+            deviceId: 0,
+            source: Source::Keyboard as i32,
+            action: u32::from(action) as i32,
+            eventTime: now,
+            downTime: now,
+            flags: 0,
+            metaState: 0,
+            modifiers: 0,
+            repeatCount: 0,
+            keyCode: u32::from(key_code) as i32,
+            scanCode: 0,
+        };
+        let host = self.host.lock().unwrap();
+        host.input_buffer.push_key_event(event);
+        host.signal_input_queue();
+    }
+
+    /// Buffers a touch screen event that will be returned by [`AndroidApp::input_events()`]
+    ///
+    /// Each pointer is given as an `(x, y)` position and pointer IDs are
+    /// assigned according to their index.
+    ///
+    /// As with `GameActivity`, buffering input doesn't wake up the application
+    /// unless an input queue has been created.
+    pub fn motion_event(&self, action: MotionAction, pointers: &[(f32, f32)]) {
+        let now = monotonic_time_nanos();
+        let mut event = GameActivityMotionEvent {
+            deviceId: 0,
+            source: Source::Touchscreen as i32,
+            action: u32::from(action) as i32,
+            eventTime: now,
+            downTime: now,
+            flags: 0,
+            metaState: 0,
+            actionButton: 0,
+            buttonState: 0,
+            classification: 0,
+            edgeFlags: 0,
+            pointerCount: 0,
+            pointers: [GameActivityPointerAxes { id: 0, axisValues: [0.0; 48], rawX: 0.0, rawY: 0.0 }; 8],
+            precisionX: 1.0,
+            precisionY: 1.0,
+        };
+        assert!(pointers.len() <= event.pointers.len(), "Too many pointers for a single motion event");
+        for (i, (x, y)) in pointers.iter().enumerate() {
+            let axes = &mut event.pointers[i];
+            axes.id = i as i32;
+            axes.axisValues[ndk_sys::AMOTION_EVENT_AXIS_X as usize] = *x;
+            axes.axisValues[ndk_sys::AMOTION_EVENT_AXIS_Y as usize] = *y;
+            axes.rawX = *x;
+            axes.rawY = *y;
+        }
+        event.pointerCount = pointers.len() as _;
+        let host = self.host.lock().unwrap();
+        host.input_buffer.push_motion_event(event);
+        host.signal_input_queue();
+    }
+
+    /// Returns `true` once the application's main function has returned
+    pub fn is_finished(&self) -> bool {
+        self.glue.is_destroyed()
+    }
+
+    /// Returns `true` once the application has asked for the activity to be
+    /// finished, via [`AndroidApp::finish()`] or by returning from its main
+    /// function
+    ///
+    /// Like the Java `Activity.finish()`, this doesn't destroy the activity by
+    /// itself, so tests can then emulate the usual [`TestActivity::pause()`],
+    /// [`TestActivity::stop()`] and [`TestActivity::destroy()`] sequence.
+    pub fn is_finishing(&self) -> bool {
+        self.finisher.is_requested()
+    }
+
+    /// Emulates `Activity.onDestroy()`, waiting for the application's main function
+    /// to return after it has been sent a [`MainEvent::Destroy`] event
+    ///
+    /// If the application thread panicked then the panic is propagated to the caller.
+    pub fn destroy(mut self) {
+        self.destroy_and_join();
+    }
+
+    fn destroy_and_join(&mut self) {
+        self.glue.destroy();
+
+        if let Some(thread) = self.thread.take() {
+            if let Err(err) = thread.join() {
+                if !thread::panicking() {
+                    std::panic::resume_unwind(err);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for TestActivity {
+    fn drop(&mut self) {
+        self.destroy_and_join();
+    }
+}
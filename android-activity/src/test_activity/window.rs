@@ -0,0 +1,72 @@
+//! A host implementation of the NDK's `ANativeWindow` size queries
+//!
+//! A fake window only tracks its size, plus whether the (emulated) Java side has
+//! destroyed the surface so that any use after `TerminateWindow` is reported as
+//! an error instead of silently working.
+
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+use ndk_sys::ANativeWindow;
+
+#[derive(Debug)]
+pub(crate) struct FakeNativeWindow {
+    width: AtomicI32,
+    height: AtomicI32,
+    destroyed: AtomicBool,
+}
+
+impl FakeNativeWindow {
+    /// Allocates a new window that is never freed
+    ///
+    /// Unlike a real `ANativeWindow`, the `ndk` crate's `NativeWindow` doesn't hold a
+    /// reference on our fake windows so we leak them to ensure a stale handle held
+    /// by a (buggy) test can't lead to a use-after-free.
+    pub(crate) fn new(width: i32, height: i32) -> &'static FakeNativeWindow {
+        Box::leak(Box::new(FakeNativeWindow {
+            width: AtomicI32::new(width),
+            height: AtomicI32::new(height),
+            destroyed: AtomicBool::new(false),
+        }))
+    }
+
+    pub(crate) fn as_ptr(&'static self) -> *mut ANativeWindow {
+        self as *const FakeNativeWindow as *mut ANativeWindow
+    }
+
+    pub(crate) fn resize(&self, width: i32, height: i32) {
+        self.width.store(width, Ordering::SeqCst);
+        self.height.store(height, Ordering::SeqCst);
+    }
+
+    pub(crate) fn destroy(&self) {
+        self.destroyed.store(true, Ordering::SeqCst);
+    }
+
+    unsafe fn from_ptr<'a>(window: *mut ANativeWindow) -> Option<&'a FakeNativeWindow> {
+        let window = &*(window as *const FakeNativeWindow);
+        if window.destroyed.load(Ordering::SeqCst) {
+            log::error!("ANativeWindow {:p} used after the window was terminated", window);
+            None
+        } else {
+            Some(window)
+        }
+    }
+}
+
+/// Returns a negative value if the window has been destroyed
+///
+/// # Safety
+/// `window` must be a pointer to a fake window
+#[no_mangle]
+pub unsafe extern "C" fn ANativeWindow_getWidth(window: *mut ANativeWindow) -> i32 {
+    FakeNativeWindow::from_ptr(window).map(|window| window.width.load(Ordering::SeqCst)).unwrap_or(-libc::EINVAL)
+}
+
+/// Returns a negative value if the window has been destroyed
+///
+/// # Safety
+/// `window` must be a pointer to a fake window
+#[no_mangle]
+pub unsafe extern "C" fn ANativeWindow_getHeight(window: *mut ANativeWindow) -> i32 {
+    FakeNativeWindow::from_ptr(window).map(|window| window.height.load(Ordering::SeqCst)).unwrap_or(-libc::EINVAL)
+}
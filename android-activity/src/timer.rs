@@ -0,0 +1,77 @@
+//! Timers that are delivered by `poll_events()`, backed by a `timerfd`
+
+use std::io;
+use std::os::unix::prelude::*;
+use std::time::Duration;
+
+/// Identifies a timer that was added via [`AndroidApp::add_timer()`](crate::AndroidApp::add_timer)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(i32);
+
+impl TimerId {
+    pub(crate) fn ident(self) -> i32 {
+        self.0
+    }
+
+    pub(crate) fn from_ident(ident: i32) -> Self {
+        Self(ident)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TimerFd {
+    fd: OwnedFd,
+}
+
+fn timespec(duration: Duration) -> libc::timespec {
+    libc::timespec {
+        tv_sec: duration.as_secs() as _,
+        tv_nsec: duration.subsec_nanos() as _,
+    }
+}
+
+impl TimerFd {
+    pub(crate) fn new() -> io::Result<Self> {
+        let fd = unsafe { libc::timerfd_create(libc::CLOCK_MONOTONIC, libc::TFD_NONBLOCK | libc::TFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { fd: unsafe { OwnedFd::from_raw_fd(fd) } })
+    }
+
+    /// Arms the timer to expire after `delay`, and then every `delay` if `repeat`
+    /// is `true`, replacing any previous schedule
+    pub(crate) fn set(&self, delay: Duration, repeat: bool) -> io::Result<()> {
+        // Re-arming the timer discards any expirations that haven't been read
+        // yet, while a zero `it_value` would disarm it instead
+        let delay = delay.max(Duration::from_nanos(1));
+        let spec = libc::itimerspec {
+            it_interval: timespec(if repeat { delay } else { Duration::ZERO }),
+            it_value: timespec(delay),
+        };
+        if unsafe { libc::timerfd_settime(self.fd.as_raw_fd(), 0, &spec, std::ptr::null_mut()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Reads the number of expirations since the last read, resetting the
+    /// readiness of the fd
+    pub(crate) fn read(&self) -> u64 {
+        let mut expirations = 0u64;
+        let n = unsafe {
+            libc::read(self.fd.as_raw_fd(), &mut expirations as *mut u64 as *mut libc::c_void, std::mem::size_of::<u64>())
+        };
+        if n == std::mem::size_of::<u64>() as isize {
+            expirations
+        } else {
+            0
+        }
+    }
+}
+
+impl AsRawFd for TimerFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
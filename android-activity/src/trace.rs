@@ -0,0 +1,186 @@
+//! Instrumentation for Perfetto and systrace, via the NDK's `ATrace` API
+//!
+//! Sections, async sections and counters are only recorded while the
+//! application is being traced (such as by Perfetto or Android Studio's CPU
+//! profiler), and otherwise each call just checks [`is_enabled()`].
+//!
+//! With the `atrace` feature the glue layer also emits sections of its own,
+//! around:
+//!
+//! - `ALooper_pollAll` within [`AndroidApp::poll_events()`](crate::AndroidApp::poll_events)
+//! - `android_app_read_cmd`, `android_app_pre_exec_cmd` and `android_app_post_exec_cmd`,
+//!   for each command from the Java main thread
+//! - `poll_events callback`, for each invocation of the application's callback
+//! - the Activity callbacks on the Java main thread, such as `onResume`
+//! - `input_events buffer swap`, within [`AndroidApp::input_events()`](crate::AndroidApp::input_events)
+//!   (`GameActivity` only)
+//!
+//! Async sections and counters require Android 10 (API level 29), and are
+//! ignored on older versions. Everything is a no-op with the `test` backend.
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+struct Api {
+    is_enabled: Option<unsafe extern "C" fn() -> bool>,
+    begin_section: Option<unsafe extern "C" fn(*const std::os::raw::c_char)>,
+    end_section: Option<unsafe extern "C" fn()>,
+    begin_async_section: Option<unsafe extern "C" fn(*const std::os::raw::c_char, i32)>,
+    end_async_section: Option<unsafe extern "C" fn(*const std::os::raw::c_char, i32)>,
+    set_counter: Option<unsafe extern "C" fn(*const std::os::raw::c_char, i64)>,
+}
+
+// The functions are looked up at runtime since they aren't all available with
+// the oldest supported version of Android
+#[cfg(not(feature = "test"))]
+fn api() -> &'static Api {
+    unsafe fn lookup<T: Copy>(name: &CStr) -> Option<T> {
+        let symbol = libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr());
+        if symbol.is_null() {
+            None
+        } else {
+            Some(std::mem::transmute_copy(&symbol))
+        }
+    }
+
+    static API: std::sync::OnceLock<Api> = std::sync::OnceLock::new();
+    API.get_or_init(|| unsafe {
+        Api {
+            is_enabled: lookup(c"ATrace_isEnabled"),
+            begin_section: lookup(c"ATrace_beginSection"),
+            end_section: lookup(c"ATrace_endSection"),
+            begin_async_section: lookup(c"ATrace_beginAsyncSection"),
+            end_async_section: lookup(c"ATrace_endAsyncSection"),
+            set_counter: lookup(c"ATrace_setCounter"),
+        }
+    })
+}
+
+#[cfg(feature = "test")]
+fn api() -> &'static Api {
+    static API: Api = Api {
+        is_enabled: None,
+        begin_section: None,
+        end_section: None,
+        begin_async_section: None,
+        end_async_section: None,
+        set_counter: None,
+    };
+    &API
+}
+
+// Section names can't contain nul bytes, so they're replaced
+fn to_cstring(name: &str) -> CString {
+    CString::new(name.replace('\0', "\u{fffd}")).unwrap()
+}
+
+/// Returns whether the application is currently being traced
+pub fn is_enabled() -> bool {
+    api().is_enabled.is_some_and(|is_enabled| unsafe { is_enabled() })
+}
+
+// Begins a section if tracing is enabled, returning whether it was begun
+pub(crate) fn begin_cstr_section(name: &CStr) -> bool {
+    match api().begin_section {
+        Some(begin_section) if is_enabled() => {
+            unsafe { begin_section(name.as_ptr()) };
+            true
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn end_cstr_section() {
+    if let Some(end_section) = api().end_section {
+        unsafe { end_section() };
+    }
+}
+
+thread_local! {
+    // Whether each section that's open via `begin_section()` on this thread was
+    // actually begun, so that `end_section()` stays balanced with it even if
+    // tracing starts or stops in between
+    static OPEN_SECTIONS: RefCell<Vec<bool>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Begins a section on the calling thread, which must be ended by calling
+/// [`end_section()`] on the same thread
+///
+/// Sections can be nested, but not interleaved. Prefer [`section()`], which
+/// ends the section when it's dropped.
+pub fn begin_section(name: &str) {
+    let begun = is_enabled() && begin_cstr_section(&to_cstring(name));
+    OPEN_SECTIONS.with(|sections| sections.borrow_mut().push(begun));
+}
+
+/// Ends the most recent section that was begun on the calling thread
+///
+/// Nothing is ended if tracing wasn't enabled when the section began.
+pub fn end_section() {
+    if OPEN_SECTIONS.with(|sections| sections.borrow_mut().pop()) == Some(true) {
+        end_cstr_section();
+    }
+}
+
+/// A section that ends when it's dropped, as returned by [`section()`]
+#[derive(Debug)]
+#[must_use = "the section ends as soon as it's dropped"]
+pub struct Section {
+    begun: bool,
+
+    // Sections must end on the thread that they began on
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for Section {
+    fn drop(&mut self) {
+        if self.begun {
+            end_cstr_section();
+        }
+    }
+}
+
+/// Begins a section on the calling thread that ends when the returned
+/// [`Section`] is dropped
+///
+/// ```no_run
+/// # fn render() {}
+/// let _section = android_activity::trace::section("render");
+/// render();
+/// ```
+pub fn section(name: &str) -> Section {
+    Section { begun: is_enabled() && begin_cstr_section(&to_cstring(name)), _not_send: PhantomData }
+}
+
+// A section that's emitted by the glue layer with the `atrace` feature
+pub(crate) fn glue_section(name: &CStr) -> Section {
+    Section { begun: cfg!(feature = "atrace") && begin_cstr_section(name), _not_send: PhantomData }
+}
+
+/// Begins an async section, which can end on any thread
+///
+/// Unlike [`begin_section()`], async sections can overlap, so each one is
+/// identified by its `name` along with a `cookie` that's passed to
+/// [`end_async_section()`].
+pub fn begin_async_section(name: &str, cookie: i32) {
+    if let Some(begin_async_section) = api().begin_async_section.filter(|_| is_enabled()) {
+        unsafe { begin_async_section(to_cstring(name).as_ptr(), cookie) };
+    }
+}
+
+/// Ends an async section that was begun with the same `name` and `cookie`
+pub fn end_async_section(name: &str, cookie: i32) {
+    if let Some(end_async_section) = api().end_async_section.filter(|_| is_enabled()) {
+        unsafe { end_async_section(to_cstring(name).as_ptr(), cookie) };
+    }
+}
+
+/// Sets the value of the counter with the given `name`, which is shown as a
+/// track of its own
+pub fn set_counter(name: &str, value: i64) {
+    if let Some(set_counter) = api().set_counter.filter(|_| is_enabled()) {
+        unsafe { set_counter(to_cstring(name).as_ptr(), value) };
+    }
+}
@@ -0,0 +1,62 @@
+//! Memory pressure levels, as delivered with [`MainEvent::LowMemory`](crate::MainEvent::LowMemory)
+
+/// How much memory the application should try to release
+///
+/// These follow the `TRIM_MEMORY_*` levels of Android's
+/// [`ComponentCallbacks2`](https://developer.android.com/reference/android/content/ComponentCallbacks2)
+/// and are ordered by severity, so that for example `level >= TrimMemoryLevel::Background`
+/// is true whenever the application is at risk of being killed to free memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+#[repr(i32)]
+pub enum TrimMemoryLevel {
+    /// The application is running but the device is starting to run low on memory
+    RunningModerate = 5,
+
+    /// The application is running but the device is running much lower on memory
+    RunningLow = 10,
+
+    /// The application is running but the device is extremely low on memory,
+    /// and background processes are being killed
+    RunningCritical = 15,
+
+    /// The application's UI is no longer visible, which is a good time to
+    /// release resources that are only needed for the UI
+    UiHidden = 20,
+
+    /// The application is in the background and near the start of the list of
+    /// processes to kill
+    Background = 40,
+
+    /// The application is in the background and near the middle of the list of
+    /// processes to kill
+    Moderate = 60,
+
+    /// The application is in the background and will be one of the first
+    /// processes to be killed
+    ///
+    /// This is also the level that's reported for `NativeActivity`'s
+    /// `onLowMemory` callback, which doesn't provide a level.
+    Complete = 80,
+}
+
+impl TrimMemoryLevel {
+    /// Maps a `TRIM_MEMORY_*` value to a level, rounding any value that isn't
+    /// known down to the nearest known level, as recommended by Android
+    pub(crate) fn from_raw(level: i32) -> Self {
+        match level {
+            80.. => TrimMemoryLevel::Complete,
+            60.. => TrimMemoryLevel::Moderate,
+            40.. => TrimMemoryLevel::Background,
+            20.. => TrimMemoryLevel::UiHidden,
+            15.. => TrimMemoryLevel::RunningCritical,
+            10.. => TrimMemoryLevel::RunningLow,
+            _ => TrimMemoryLevel::RunningModerate,
+        }
+    }
+
+    /// The `TRIM_MEMORY_*` value for the level
+    pub fn to_raw(self) -> i32 {
+        self as i32
+    }
+}
@@ -0,0 +1,148 @@
+//! Typed events that are sent from other threads and delivered by `poll_events()`
+//!
+//! All the channels created by `AndroidApp::create_event_channel()` share a
+//! single queue, so that events are delivered in the order they were sent, and
+//! an `eventfd` that's registered with the looper. The `eventfd` is used as a
+//! semaphore so that the looper is woken once for each event.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::os::unix::prelude::*;
+use std::sync::mpsc::SendError;
+use std::sync::{Arc, Mutex};
+
+/// An event that was sent via an [`EventSender`], which is delivered by
+/// [`AndroidApp::poll_events()`](crate::AndroidApp::poll_events) as a
+/// [`PollEvent::User`](crate::PollEvent::User)
+pub struct UserEvent(Box<dyn Any + Send>);
+
+impl UserEvent {
+    /// Returns `true` if the event was sent by an [`EventSender<T>`]
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    /// Returns a reference to the event if it was sent by an [`EventSender<T>`]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+
+    /// Takes the event if it was sent by an [`EventSender<T>`], or otherwise
+    /// returns the `UserEvent` back
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        self.0.downcast().map(|event| *event).map_err(Self)
+    }
+}
+
+impl fmt::Debug for UserEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserEvent").finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+struct QueueState {
+    events: VecDeque<UserEvent>,
+    closed: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct UserEventQueue {
+    state: Mutex<QueueState>,
+    eventfd: OwnedFd,
+}
+
+impl UserEventQueue {
+    pub(crate) fn new() -> io::Result<Self> {
+        let fd = unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK | libc::EFD_SEMAPHORE) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            state: Mutex::new(QueueState { events: VecDeque::new(), closed: false }),
+            eventfd: unsafe { OwnedFd::from_raw_fd(fd) },
+        })
+    }
+
+    fn push<T: Send + 'static>(&self, event: T) -> Result<(), SendError<T>> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return Err(SendError(event));
+        }
+        state.events.push_back(UserEvent(Box::new(event)));
+
+        let count = 1u64;
+        unsafe {
+            libc::write(self.eventfd.as_raw_fd(), &count as *const u64 as *const libc::c_void, std::mem::size_of::<u64>());
+        }
+        Ok(())
+    }
+
+    /// Takes the next event, once the looper has reported that the `eventfd` is
+    /// readable
+    pub(crate) fn pop(&self) -> Option<UserEvent> {
+        let mut count = 0u64;
+        let n = unsafe {
+            libc::read(self.eventfd.as_raw_fd(), &mut count as *mut u64 as *mut libc::c_void, std::mem::size_of::<u64>())
+        };
+        if n != std::mem::size_of::<u64>() as isize {
+            return None;
+        }
+        self.state.lock().unwrap().events.pop_front()
+    }
+
+    /// Drops any queued events, and any events that are sent later, once the
+    /// application's looper source is removed
+    pub(crate) fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        state.events.clear();
+    }
+}
+
+impl AsRawFd for UserEventQueue {
+    fn as_raw_fd(&self) -> RawFd {
+        self.eventfd.as_raw_fd()
+    }
+}
+
+/// Sends events of type `T` from any thread to the application's main loop
+///
+/// Created via [`AndroidApp::create_event_channel()`](crate::AndroidApp::create_event_channel).
+/// Each event that's sent wakes up the looper and is delivered by
+/// [`AndroidApp::poll_events()`](crate::AndroidApp::poll_events) as a
+/// [`PollEvent::User`](crate::PollEvent::User), in the order that events were
+/// sent (across all channels).
+pub struct EventSender<T> {
+    queue: Arc<UserEventQueue>,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T: Send + 'static> EventSender<T> {
+    pub(crate) fn new(queue: Arc<UserEventQueue>) -> Self {
+        Self { queue, _marker: PhantomData }
+    }
+
+    /// Queues an event and wakes up the application's main loop
+    ///
+    /// Returns the event back as an error if the application's main loop has
+    /// already finished (once the [`AndroidApp`](crate::AndroidApp) has been dropped).
+    pub fn send(&self, event: T) -> Result<(), SendError<T>> {
+        self.queue.push(event)
+    }
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        Self { queue: self.queue.clone(), _marker: PhantomData }
+    }
+}
+
+impl<T> fmt::Debug for EventSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventSender").finish_non_exhaustive()
+    }
+}
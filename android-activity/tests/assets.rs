@@ -0,0 +1,96 @@
+//! Exercises the `Assets` API via the host-only `test` backend, which serves
+//! assets from a local directory
+
+use std::fs;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use android_activity::test_activity::TestActivity;
+use android_activity::{AssetEntry, Assets, MainEvent, PollEvent};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// Creates a unique directory of assets for a test
+fn assets_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("android-activity-assets-{}-{test}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("shaders/post")).unwrap();
+    fs::write(dir.join("hello.txt"), b"Hello, assets").unwrap();
+    fs::write(dir.join("empty"), b"").unwrap();
+    fs::write(dir.join("shaders/main.wgsl"), b"fn main() {}").unwrap();
+    fs::write(dir.join("shaders/post/blur.wgsl"), b"fn blur() {}").unwrap();
+    dir
+}
+
+// Runs an activity serving `dir` and returns its `Assets`
+fn app_assets(dir: PathBuf) -> Assets {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| match event {
+                PollEvent::Main(MainEvent::Start) => tx.send(app.assets()).unwrap(),
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+        }
+    });
+    activity.set_assets_dir(dir);
+    activity.start();
+    let assets = rx.recv_timeout(TIMEOUT).expect("Timed out waiting for assets");
+    activity.destroy();
+    assets
+}
+
+#[test]
+fn read_and_seek() {
+    let assets = Assets::from_dir(assets_dir("read"));
+    assert_eq!(assets.read("hello.txt").unwrap(), b"Hello, assets");
+    assert_eq!(assets.read("/shaders/./main.wgsl").unwrap(), b"fn main() {}");
+    assert_eq!(assets.read("empty").unwrap(), b"");
+
+    let mut file = assets.open("hello.txt").unwrap();
+    assert_eq!(file.len(), 13);
+    file.seek(SeekFrom::Start(7)).unwrap();
+    let mut rest = String::new();
+    file.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "assets");
+
+    assert_eq!(assets.open("missing.txt").unwrap_err().kind(), ErrorKind::NotFound);
+    assert_eq!(assets.open("shaders").unwrap_err().kind(), ErrorKind::NotFound);
+    assert_eq!(assets.open("../hello.txt").unwrap_err().kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn directory_listing() {
+    let assets = Assets::from_dir(assets_dir("listing"));
+    assert_eq!(assets.read_dir("shaders").unwrap(), vec![
+        AssetEntry { path: "shaders/main.wgsl".to_string(), is_dir: false },
+        AssetEntry { path: "shaders/post".to_string(), is_dir: true },
+    ]);
+    assert_eq!(assets.walk_dir("").unwrap(), vec!["empty", "hello.txt", "shaders/main.wgsl", "shaders/post/blur.wgsl"]);
+    assert_eq!(assets.walk_dir("shaders/post/").unwrap(), vec!["shaders/post/blur.wgsl"]);
+
+    // Like `AssetManager.list()` on a device, there are no entries for a missing
+    // directory or a file
+    assert_eq!(assets.read_dir("missing").unwrap(), vec![]);
+    assert_eq!(assets.read_dir("hello.txt").unwrap(), vec![]);
+    assert_eq!(assets.walk_dir("missing").unwrap(), Vec::<String>::new());
+}
+
+#[test]
+fn memory_mapped() {
+    let assets = Assets::from_dir(assets_dir("map"));
+    assert_eq!(&*assets.map("shaders/post/blur.wgsl").unwrap(), b"fn blur() {}");
+    assert_eq!(&*assets.map("empty").unwrap(), b"");
+}
+
+#[test]
+fn background_loading() {
+    let assets = app_assets(assets_dir("load"));
+    assert_eq!(assets.load("hello.txt").wait().unwrap(), b"Hello, assets");
+    assert_eq!(futures::executor::block_on(assets.load("shaders/main.wgsl")).unwrap(), b"fn main() {}");
+    assert_eq!(assets.load("missing.txt").wait().unwrap_err().kind(), ErrorKind::NotFound);
+}
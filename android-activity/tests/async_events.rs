@@ -0,0 +1,253 @@
+//! Exercises the `async` event stream and executor via the host-only `test` backend
+
+use std::cell::Cell;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::os::unix::prelude::*;
+use std::rc::Rc;
+use std::sync::mpsc;
+use std::task::Poll;
+use std::thread;
+use std::time::Duration;
+
+use android_activity::async_events::{Event, Executor};
+use android_activity::test_activity::TestActivity;
+use futures::channel::oneshot;
+use futures::StreamExt;
+use ndk::looper::FdEvent;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn lifecycle_events() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(Some(b"restored"), move |app| {
+        let executor = Executor::new(&app);
+        executor.block_on(async {
+            let mut events = app.events();
+            while let Some(event) = events.next().await {
+                if let Event::SaveState(request) = &event {
+                    request.store(b"saved");
+                }
+                tx.send(format!("{event:?}")).unwrap();
+            }
+            tx.send("end of stream".to_string()).unwrap();
+        });
+    });
+    let recv = || rx.recv_timeout(TIMEOUT).expect("Timed out waiting for application event");
+
+    activity.start();
+    assert_eq!(recv(), "Start");
+    activity.resume();
+    assert_eq!(recv(), format!("Resume {{ saved_state: Some({:?}) }}", b"restored"));
+    activity.pause();
+    assert_eq!(recv(), "Pause");
+    assert_eq!(activity.save_instance_state().as_deref(), Some(&b"saved"[..]));
+    assert!(recv().starts_with("SaveState"));
+
+    activity.destroy();
+    assert_eq!(recv(), "Destroy");
+    assert_eq!(recv(), "end of stream");
+}
+
+#[test]
+fn terminate_window_waits_for_completion() {
+    let (tx, rx) = mpsc::channel();
+    let (finish_tx, finish_rx) = oneshot::channel::<()>();
+    let activity = TestActivity::create(None, move |app| {
+        let executor = Executor::new(&app);
+        executor.block_on(async {
+            let mut events = app.events();
+            while let Some(event) = events.next().await {
+                match event {
+                    Event::InitWindow => tx.send(app.native_window().unwrap()).unwrap(),
+                    Event::TerminateWindow(completion) => {
+                        // Wake ups from other threads let us finish with the window
+                        // before completing the event
+                        finish_rx.await.unwrap();
+                        completion.complete();
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+        });
+    });
+
+    activity.native_window_created(640, 480);
+    let window = rx.recv_timeout(TIMEOUT).unwrap();
+
+    thread::scope(|scope| {
+        let destroyed = scope.spawn(|| activity.native_window_destroyed());
+
+        // The Java side is blocked until the completion is dropped
+        thread::sleep(Duration::from_millis(50));
+        assert!(!destroyed.is_finished());
+        assert_eq!(window.width(), Ok(640));
+
+        finish_tx.send(()).unwrap();
+        destroyed.join().unwrap();
+    });
+    assert!(window.is_terminated());
+    activity.destroy();
+}
+
+#[test]
+fn window_replaced_while_terminate_window_is_pending() {
+    let (tx, rx) = mpsc::channel();
+    let (finish_tx, finish_rx) = oneshot::channel::<()>();
+    let activity = TestActivity::create(None, move |app| {
+        let executor = Executor::new(&app);
+        executor.block_on(async {
+            let mut finish_rx = Some(finish_rx);
+            let mut events = app.events();
+            while let Some(event) = events.next().await {
+                match event {
+                    Event::InitWindow => tx.send(app.native_window().unwrap()).unwrap(),
+                    Event::TerminateWindow(completion) => {
+                        // The InitWindow for the new window mustn't be handled
+                        // until the old window has been terminated
+                        finish_rx.take().unwrap().await.unwrap();
+                        completion.complete();
+                    }
+                    _ => {}
+                }
+            }
+        });
+    });
+
+    activity.native_window_created(640, 480);
+    let old_window = rx.recv_timeout(TIMEOUT).unwrap();
+
+    thread::scope(|scope| {
+        let replaced = scope.spawn(|| activity.native_window_replaced(800, 600));
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(!replaced.is_finished());
+        assert!(rx.try_recv().is_err());
+        assert_eq!(old_window.width(), Ok(640));
+
+        finish_tx.send(()).unwrap();
+        replaced.join().unwrap();
+    });
+    let new_window = rx.recv_timeout(TIMEOUT).unwrap();
+    assert!(old_window.is_terminated());
+    assert!(!new_window.is_terminated());
+    assert_eq!(new_window.width(), Ok(800));
+    activity.destroy();
+}
+
+#[test]
+fn spawned_tasks() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let executor = Executor::new(&app);
+        let polled = Rc::new(Cell::new(0));
+
+        let (sender, receiver) = oneshot::channel();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            sender.send(42).unwrap();
+        });
+        let task_polled = polled.clone();
+        executor.spawn(async move {
+            task_polled.set(receiver.await.unwrap());
+        });
+
+        // Spawned tasks run while blocking on another future
+        let result = executor.block_on(async {
+            let mut events = app.events();
+            while let Some(event) = events.next().await {
+                if let Event::Start = event {
+                    break;
+                }
+            }
+            "done"
+        });
+        tx.send((result, polled.get())).unwrap();
+    });
+
+    thread::sleep(Duration::from_millis(50));
+    activity.start();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("done", 42)));
+    activity.destroy();
+}
+
+#[test]
+fn readable_fd_does_not_block_stream() {
+    let (tx, rx) = mpsc::channel();
+    let (mut writer, reader) = UnixStream::pair().unwrap();
+    let activity = TestActivity::create(None, move |app| {
+        // The fd is never read, so it stays readable
+        let token = unsafe { app.register_fd(reader.as_raw_fd(), FdEvent::INPUT) }.unwrap();
+        let executor = Executor::new(&app);
+        executor.block_on(async {
+            let mut events = app.events();
+            let mut fd_events = 0;
+            while let Some(event) = events.next().await {
+                match event {
+                    Event::Fd { .. } => {
+                        fd_events += 1;
+                        if fd_events == 2 {
+                            tx.send("Fd".to_string()).unwrap();
+                        }
+                    }
+                    Event::Start => {
+                        tx.send("Start".to_string()).unwrap();
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        });
+        app.unregister_fd(token);
+    });
+
+    writer.write_all(b"x").unwrap();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok("Fd".to_string()));
+    activity.start();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok("Start".to_string()));
+    activity.destroy();
+}
+
+#[test]
+fn idle_stream_waits_for_events() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let sender = app.create_event_channel::<u32>().unwrap();
+        thread::spawn(move || {
+            for value in [1, 2] {
+                thread::sleep(Duration::from_millis(200));
+                sender.send(value).unwrap();
+            }
+        });
+        let executor = Executor::new(&app);
+        let polls = executor.block_on(async {
+            let mut events = app.events();
+            let mut polls = 0;
+
+            // Nothing is pending between the user events, so the stream should
+            // only be polled a few times while it waits for each of them
+            for _ in 0..2 {
+                futures::future::poll_fn(|cx| {
+                    polls += 1;
+                    match events.poll_next_unpin(cx) {
+                        Poll::Ready(Some(Event::User(_))) => Poll::Ready(()),
+                        Poll::Ready(_) => {
+                            cx.waker().wake_by_ref();
+                            Poll::Pending
+                        }
+                        Poll::Pending => Poll::Pending,
+                    }
+                })
+                .await;
+            }
+            polls
+        });
+        tx.send(polls).unwrap();
+    });
+
+    let polls = rx.recv_timeout(TIMEOUT).unwrap();
+    assert!(polls < 10, "The stream was polled {polls} times while idle");
+    activity.destroy();
+}
@@ -0,0 +1,90 @@
+//! Exercises the logcat logger via the host-only `test` backend, which emulates
+//! logcat
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use android_activity::logger;
+use android_activity::test_activity::{logcat_messages, LogcatMessage, TestActivity};
+use log::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// Runs `main` as an application, which has the logger installed by the glue
+fn run(main: impl FnOnce() + Send + 'static) {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |_app| {
+        main();
+        tx.send(()).unwrap();
+    });
+    rx.recv_timeout(TIMEOUT).expect("Timed out waiting for application");
+    activity.destroy();
+}
+
+fn messages_with_tag(tag: &str) -> Vec<LogcatMessage> {
+    logcat_messages().into_iter().filter(|message| message.tag == tag).collect()
+}
+
+// The tests share a single logger (and maximum level) so they're all run
+// from one test
+#[test]
+fn logger() {
+    run(|| {
+        // Tags default to the crate name, or can be chosen per module
+        logger::set_module_tag("logger::audio", "Audio");
+        log::info!(target: "logger::audio::mixer", "Mixer started");
+        log::warn!(target: "logger::audiovisual", "Not audio");
+        log::error!("From the crate");
+
+        // Long messages are split, preferably at newlines
+        let long = format!("{}\n{}", "a".repeat(3000), "b".repeat(5000));
+        log::info!(target: "long", "{long}");
+
+        // The maximum level is adjustable
+        logger::set_max_level(LevelFilter::Warn);
+        log::info!(target: "filtered", "Dropped");
+        log::warn!(target: "filtered", "Kept");
+        logger::set_max_level(LevelFilter::Trace);
+
+        // `tracing` events
+        let subscriber = tracing_subscriber::registry().with(logger::layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "traced", frame = 3, "Frame {}", "done");
+
+            logger::set_max_level(LevelFilter::Warn);
+            tracing::info!(target: "traced_filtered", "Dropped");
+            tracing::warn!(target: "traced_filtered", "Kept");
+            logger::set_max_level(LevelFilter::Trace);
+
+            // The layer doesn't depend on the `log` level
+            log::set_max_level(LevelFilter::Off);
+            tracing::info!(target: "traced_without_log", "Kept");
+            log::set_max_level(LevelFilter::Trace);
+        });
+    });
+
+    let audio = messages_with_tag("Audio");
+    assert_eq!(audio.len(), 1);
+    assert_eq!(audio[0].message, "Mixer started");
+    assert_eq!(audio[0].priority, ndk_sys::android_LogPriority_ANDROID_LOG_INFO as i32);
+    assert!(logcat_messages().iter().any(|message| message.tag == "logger" && message.message == "Not audio"));
+    assert!(logcat_messages().iter().any(|message| message.tag == "logger" && message.message == "From the crate"));
+
+    let long: Vec<_> = messages_with_tag("long").into_iter().map(|message| message.message).collect();
+    assert_eq!(long.len(), 3);
+    assert_eq!(long[0], "a".repeat(3000));
+    assert_eq!(long.concat(), format!("{}{}", "a".repeat(3000), "b".repeat(5000)));
+    assert!(long.iter().all(|message| message.len() < 4068));
+
+    let filtered: Vec<_> = messages_with_tag("filtered").into_iter().map(|message| message.message).collect();
+    assert_eq!(filtered, vec!["Kept"]);
+
+    let traced = messages_with_tag("traced");
+    assert_eq!(traced.len(), 1);
+    assert_eq!(traced[0].message, "Frame done frame=3");
+
+    let filtered: Vec<_> = messages_with_tag("traced_filtered").into_iter().map(|message| message.message).collect();
+    assert_eq!(filtered, vec!["Kept"]);
+    assert_eq!(messages_with_tag("traced_without_log").len(), 1);
+}
@@ -0,0 +1,299 @@
+//! Exercises recording and replaying events via the host-only `test` backend
+
+use std::io::{Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Sender};
+use std::time::Duration;
+
+use android_activity::input::{InputEvent, KeyAction, Keycode, MotionAction};
+use android_activity::recording::{Record, RecordedEvent, Recording};
+use android_activity::test_activity::TestActivity;
+use android_activity::{AndroidApp, LifecycleState, MainEvent, PollEvent, TrimMemoryLevel};
+use ndk::looper::FdEvent;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn recording_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("android-activity-{}-{name}.rec", std::process::id()))
+}
+
+// Reports a description of every event the application sees until it's destroyed,
+// after either starting a recording or a replay
+fn app_main(tx: Sender<String>, record_to: Option<PathBuf>, replay: Option<Recording>) -> impl FnOnce(AndroidApp) + Send + 'static {
+    move |app: AndroidApp| {
+        if let Some(path) = &record_to {
+            app.start_recording(path).unwrap();
+        }
+        if let Some(recording) = replay {
+            app.start_replay(recording);
+        }
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(Some(Duration::from_millis(500)), |event| {
+                let description = match event {
+                    PollEvent::Wake => {
+                        app.input_events(|event| {
+                            let description = match event {
+                                InputEvent::KeyEvent(key) => format!("Key({:?}, {:?})", key.action(), key.key_code()),
+                                InputEvent::MotionEvent(motion) => format!(
+                                    "Motion({:?}, {:?})",
+                                    motion.action(),
+                                    motion.pointers().map(|pointer| (pointer.x(), pointer.y())).collect::<Vec<_>>()
+                                ),
+                                _ => return,
+                            };
+                            tx.send(description).unwrap();
+                        });
+                        "Wake".to_string()
+                    }
+                    PollEvent::Main(MainEvent::Resume { loader, .. }) => format!("Resume({:?})", loader.load()),
+                    PollEvent::Main(MainEvent::SaveState { saver, .. }) => {
+                        saver.store(b"saved");
+                        "SaveState".to_string()
+                    }
+                    PollEvent::Main(MainEvent::Destroy) => {
+                        destroyed = true;
+                        "Destroy".to_string()
+                    }
+                    PollEvent::Main(MainEvent::ConfigChanged { diff, .. }) => format!("ConfigChanged({diff:?})"),
+                    PollEvent::Main(main_event) => format!("{main_event:?}"),
+                    PollEvent::Timeout => return,
+                    event => format!("{event:?}"),
+                };
+                tx.send(description).unwrap();
+            });
+        }
+        if record_to.is_some() {
+            app.stop_recording().unwrap();
+        }
+    }
+}
+
+#[test]
+fn record_and_replay() {
+    let path = recording_path("record_and_replay");
+
+    let (tx, rx) = mpsc::channel();
+    let (waker_tx, waker_rx) = mpsc::channel();
+    let record = app_main(tx, Some(path.clone()), None);
+    let activity = TestActivity::create(Some(b"restored"), move |app| {
+        waker_tx.send(app.create_waker()).unwrap();
+        record(app)
+    });
+    let waker = waker_rx.recv_timeout(TIMEOUT).unwrap();
+
+    // A rotation during a save, followed by the loss of the window
+    activity.start();
+    activity.resume();
+    activity.native_window_created(640, 480);
+    activity.key_event(KeyAction::Down, Keycode::A);
+    activity.motion_event(MotionAction::Down, &[(1.0, 2.0)]);
+    waker.wake();
+    activity.pause();
+    activity.native_window_resized(800, 600);
+    activity.trim_memory(TrimMemoryLevel::UiHidden);
+    activity.configuration_changed(|config| config.orientation = ndk_sys::ACONFIGURATION_ORIENTATION_LAND as i32);
+    assert_eq!(activity.save_instance_state().as_deref(), Some(&b"saved"[..]));
+    activity.native_window_destroyed();
+    activity.stop();
+    activity.destroy();
+    let recorded: Vec<String> = rx.iter().collect();
+
+    let recording = Recording::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(recording.records()[2].event, RecordedEvent::InitWindow { width: 640, height: 480 }));
+    assert!(recording.records().windows(2).all(|records| records[0].time <= records[1].time));
+
+    // Replaying the recording delivers the same events, without any help from the activity
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, app_main(tx, None, Some(recording)));
+    let replayed: Vec<String> = rx.iter().take(recorded.len()).collect();
+    activity.destroy();
+
+    assert_eq!(replayed, recorded);
+    assert_eq!(
+        recorded,
+        vec![
+            "Start",
+            "Resume(Some([114, 101, 115, 116, 111, 114, 101, 100]))",
+            "InitWindow",
+            "Key(Down, A)",
+            "Motion(Down, [(1.0, 2.0)])",
+            "Wake",
+            "Pause",
+            "WindowResized { old_size: Size { width: 640, height: 480 }, new_size: Size { width: 800, height: 600 } }",
+            "LowMemory { level: UiHidden }",
+            "ConfigChanged(ORIENTATION)",
+            "SaveState",
+            "TerminateWindow",
+            "Stop",
+            "Destroy",
+        ]
+    );
+}
+
+#[test]
+fn real_events_are_hidden_while_replaying() {
+    let (tx, rx) = mpsc::channel();
+    let recording = Recording::from(vec![Record { time: Duration::from_millis(200), event: RecordedEvent::LowMemory(TrimMemoryLevel::Complete) }]);
+    let activity = TestActivity::create(None, move |app| {
+        app.start_replay(recording);
+        let mut delivered = 0;
+        while delivered < 2 {
+            app.poll_events(Some(TIMEOUT), |event| {
+                if !matches!(event, PollEvent::Timeout) {
+                    delivered += 1;
+                    tx.send((format!("{event:?}"), app.is_replaying())).unwrap();
+                }
+            });
+        }
+    });
+
+    // Real events are handled by the glue layer but only the replayed event
+    // is delivered, until the end of the recording
+    activity.start();
+    activity.window_focus_changed(true);
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("Main(LowMemory { level: Complete })".to_string(), true)));
+    activity.window_focus_changed(false);
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("Main(LostFocus)".to_string(), false)));
+    activity.destroy();
+}
+
+#[test]
+fn real_destroy_ends_replay() {
+    let (tx, rx) = mpsc::channel();
+    let recording = Recording::from(vec![Record { time: Duration::from_secs(60), event: RecordedEvent::LowMemory(TrimMemoryLevel::Complete) }]);
+    let activity = TestActivity::create(None, move |app| {
+        let events = app.create_event_channel::<u32>().unwrap();
+        app.start_replay(recording);
+        events.send(7).unwrap();
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(Some(TIMEOUT), |event| {
+                let description = match event {
+                    PollEvent::User(event) => format!("User({:?})", event.downcast_ref::<u32>()),
+                    PollEvent::Main(MainEvent::Destroy) => {
+                        destroyed = true;
+                        "Destroy".to_string()
+                    }
+                    PollEvent::Main(main_event) => format!("{main_event:?}"),
+                    _ => return,
+                };
+                tx.send((description, app.is_replaying(), app.lifecycle_state())).unwrap();
+            });
+        }
+    });
+
+    // Real user events are delivered without ending the replay, but a real
+    // destroy can't be hidden from the application
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("User(Some(7))".to_string(), true, LifecycleState::Created)));
+    activity.start();
+    activity.destroy();
+    let events: Vec<_> = rx.iter().collect();
+    assert_eq!(events.last(), Some(&("Destroy".to_string(), false, LifecycleState::Destroyed)));
+}
+
+#[test]
+fn replayed_lifecycle_events_keep_the_real_state() {
+    let (tx, rx) = mpsc::channel();
+    let recording = Recording::from(vec![
+        Record { time: Duration::from_millis(200), event: RecordedEvent::Stop },
+        Record { time: Duration::from_millis(200), event: RecordedEvent::Destroy },
+    ]);
+    let activity = TestActivity::create(None, move |app| {
+        app.start_replay(recording);
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(Some(TIMEOUT), |event| {
+                if let PollEvent::Main(main_event) = event {
+                    destroyed = matches!(main_event, MainEvent::Destroy);
+                    tx.send((format!("{main_event:?}"), app.lifecycle_state())).unwrap();
+                }
+            });
+        }
+    });
+
+    // The real events that are hidden by the replay are still tracked, and
+    // returning after the replayed `Destroy` still finishes the real Activity
+    activity.start();
+    activity.resume();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("Stop".to_string(), LifecycleState::Resumed)));
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("Destroy".to_string(), LifecycleState::Resumed)));
+    while !activity.is_finished() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert!(activity.is_finishing());
+    activity.destroy();
+}
+
+#[test]
+fn requested_events_are_delivered_while_replaying() {
+    let (tx, rx) = mpsc::channel();
+    let (mut writer, reader) = UnixStream::pair().unwrap();
+    let recording = Recording::from(vec![Record { time: Duration::from_secs(60), event: RecordedEvent::LowMemory(TrimMemoryLevel::Complete) }]);
+    let activity = TestActivity::create(None, move |app| {
+        let mut reader = reader;
+        let token = unsafe { app.register_fd(reader.as_raw_fd(), FdEvent::INPUT) }.unwrap();
+        app.start_replay(recording);
+        app.request_frame().unwrap();
+        let mut frames = 0;
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(Some(TIMEOUT), |event| {
+                let description = match event {
+                    PollEvent::Fd { token: event_token, .. } => {
+                        assert_eq!(event_token, token);
+                        let mut buf = [0u8; 5];
+                        reader.read_exact(&mut buf).unwrap();
+                        format!("Fd({:?})", std::str::from_utf8(&buf).unwrap())
+                    }
+                    // An application that requests each frame from the last one
+                    // keeps rendering
+                    PollEvent::Frame { .. } => {
+                        frames += 1;
+                        if frames < 3 {
+                            app.request_frame().unwrap();
+                            return;
+                        }
+                        "Frames".to_string()
+                    }
+                    PollEvent::Main(MainEvent::SaveState { saver, .. }) => {
+                        saver.store(b"real state");
+                        "SaveState".to_string()
+                    }
+                    PollEvent::Main(MainEvent::Destroy) => {
+                        destroyed = true;
+                        return;
+                    }
+                    _ => return,
+                };
+                tx.send((description, app.is_replaying())).unwrap();
+            });
+        }
+    });
+
+    activity.start();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("Frames".to_string(), true)));
+    writer.write_all(b"hello").unwrap();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("Fd(\"hello\")".to_string(), true)));
+    activity.pause();
+    activity.stop();
+    assert_eq!(activity.save_instance_state(), Some(b"real state".to_vec()));
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(("SaveState".to_string(), true)));
+    activity.destroy();
+}
+
+#[test]
+fn invalid_recordings() {
+    assert!(Recording::read_from(&b"not a recording"[..]).is_err());
+    assert!(Recording::read_from(&b"AAREC\0\x02"[..]).is_err());
+    assert!(Recording::read_from(&b"AAREC\0\x03\x00\xff"[..]).is_err());
+    assert_eq!(Recording::read_from(&b"AAREC\0\x03"[..]).unwrap().records().len(), 0);
+
+    // A truncated trailing record (here an `InitWindow` without its height) is
+    // dropped, leaving the complete records before it
+    let recording = Recording::read_from(&b"AAREC\0\x03\x00\x00\x01\x04\x02"[..]).unwrap();
+    assert!(matches!(recording.records(), [Record { event: RecordedEvent::Wake, .. }]));
+}
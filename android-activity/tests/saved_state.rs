@@ -0,0 +1,125 @@
+//! Exercises versioned, serialized saved state via the host-only `test` backend
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use android_activity::test_activity::TestActivity;
+use android_activity::{MainEvent, PollEvent, SavedState, SavedStateError, StateLoader};
+use serde::{Deserialize, Serialize};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StateV1 {
+    uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StateV2 {
+    uri: String,
+    zoom: f32,
+}
+
+// Runs an activity until it has saved its state via `store_serialized()`
+fn save<T: Serialize + Send + 'static>(version: u32, state: T) -> Vec<u8> {
+    let activity = TestActivity::create(None, move |app| {
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| match event {
+                PollEvent::Main(MainEvent::SaveState { saver, .. }) => saver.store_serialized(version, &state).unwrap(),
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+        }
+    });
+    activity.start();
+    activity.resume();
+    activity.pause();
+    let saved_state = activity.save_instance_state();
+    activity.destroy();
+    saved_state.expect("No saved state")
+}
+
+// Runs an activity with the given saved state and returns what `load` made of it
+fn load<R, F>(saved_state: Option<&[u8]>, load: F) -> R
+    where R: Send + 'static,
+          F: FnOnce(&StateLoader) -> R + Send + 'static
+{
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(saved_state, move |app| {
+        let mut load = Some(load);
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| match event {
+                PollEvent::Main(MainEvent::Resume { loader, .. }) => {
+                    if let Some(load) = load.take() {
+                        tx.send(load(&loader)).unwrap();
+                    }
+                }
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+        }
+    });
+    activity.start();
+    activity.resume();
+    let loaded = rx.recv_timeout(TIMEOUT).expect("Timed out waiting for application to load its state");
+    activity.destroy();
+    loaded
+}
+
+fn migrate_v1(old: SavedState) -> Result<StateV2, SavedStateError> {
+    match old.version() {
+        1 => old.deserialize::<StateV1>().map(|state| StateV2 { uri: state.uri, zoom: 1.0 }),
+        _ => Err(old.unsupported()),
+    }
+}
+
+#[test]
+fn serialized_round_trip() {
+    let state = StateV2 { uri: "foo://bar".to_string(), zoom: 2.0 };
+    let saved_state = save(2, state.clone());
+    let loaded = load(Some(&saved_state), |loader| loader.load_deserialized::<StateV2>(2).unwrap());
+    assert_eq!(loaded, Some(state));
+
+    // No saved state isn't an error
+    let loaded = load(None, |loader| loader.load_deserialized::<StateV2>(2).unwrap());
+    assert_eq!(loaded, None);
+}
+
+#[test]
+fn older_versions_are_migrated() {
+    let saved_state = save(1, StateV1 { uri: "foo://bar".to_string() });
+
+    let loaded = load(Some(&saved_state), |loader| loader.load_deserialized_with(2, migrate_v1).unwrap());
+    assert_eq!(loaded, Some(StateV2 { uri: "foo://bar".to_string(), zoom: 1.0 }));
+
+    // Without a migration, the old version is rejected instead of being misinterpreted
+    let loaded = load(Some(&saved_state), |loader| loader.load_deserialized::<StateV2>(2).map(|_| ()));
+    assert!(matches!(loaded, Err(SavedStateError::UnsupportedVersion { version: 1, expected: 2 })));
+}
+
+#[test]
+fn invalid_state_is_rejected() {
+    let saved_state = save(2, StateV2 { uri: "foo://bar".to_string(), zoom: 2.0 });
+
+    // State from a newer version of the application
+    let loaded = load(Some(&saved_state), |loader| loader.load_deserialized_with(1, |_| -> Result<StateV1, _> { unreachable!() }).map(|_| ()));
+    assert!(matches!(loaded, Err(SavedStateError::UnsupportedVersion { version: 2, expected: 1 })));
+
+    // Corrupt or truncated state
+    let mut corrupt = saved_state.clone();
+    *corrupt.last_mut().unwrap() ^= 0xff;
+    let loaded = load(Some(&corrupt), |loader| loader.load_deserialized::<StateV2>(2).map(|_| ()));
+    assert!(matches!(loaded, Err(SavedStateError::ChecksumMismatch)));
+    let loaded = load(Some(&saved_state[..8]), |loader| loader.load_deserialized::<StateV2>(2).map(|_| ()));
+    assert!(matches!(loaded, Err(SavedStateError::InvalidHeader)));
+
+    // State that wasn't stored via `store_serialized()`
+    let loaded = load(Some(b"{\"uri\":\"foo://bar\"}"), |loader| loader.load_deserialized::<StateV2>(2).map(|_| ()));
+    assert!(matches!(loaded, Err(SavedStateError::InvalidHeader)));
+
+    // A valid header for state of the wrong type
+    let loaded = load(Some(&saved_state), |loader| loader.load_deserialized::<Vec<u32>>(2).map(|_| ()));
+    assert!(matches!(loaded, Err(SavedStateError::Serde(_))));
+}
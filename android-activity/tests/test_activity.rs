@@ -0,0 +1,909 @@
+//! Exercises the glue layer's lifecycle handshakes via the host-only `test` backend
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::os::unix::prelude::*;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+use android_activity::input::{InputEvent, KeyAction, Keycode, MotionAction};
+use android_activity::test_activity::{AndroidAppExt, TestActivity, TestConfiguration};
+use android_activity::{
+    android_app, AndroidApp, AndroidAppError, ConfigurationDiff, InsetType, LifecycleState, MainEvent, NativeWindowHandle, PollEvent, Rect, Size, TrimMemoryLevel,
+    WindowInsets, WindowTerminatedError,
+};
+use ndk::configuration::Orientation;
+use ndk::looper::FdEvent;
+
+#[derive(Debug, PartialEq)]
+enum Recorded {
+    Start,
+    Resume(Option<Vec<u8>>),
+    SaveState,
+    Pause,
+    Stop,
+    Destroy,
+    InitWindow(i32, i32),
+    TerminateWindow,
+    WindowResized(Size, Size),
+    RedrawNeeded,
+    ContentRectChanged(Rect),
+    GainedFocus,
+    LostFocus,
+    ConfigChanged(ConfigurationDiff, Orientation, Orientation),
+    LowMemory(TrimMemoryLevel),
+    InsetsChanged(WindowInsets),
+    Wake,
+    Key(KeyAction, Keycode),
+    Motion(MotionAction, Vec<(f32, f32)>),
+}
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// A minimal application main loop that records every event it sees and stores
+// `saved_state` whenever it's asked to save its state
+fn recording_app(saved_state: &'static [u8], tx: Sender<Recorded>) -> impl FnOnce(AndroidApp) + Send + 'static {
+    move |app: AndroidApp| {
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| {
+                let recorded = match event {
+                    PollEvent::Wake => {
+                        tx.send(Recorded::Wake).unwrap();
+                        app.input_events(|event| {
+                            let recorded = match event {
+                                InputEvent::KeyEvent(key) => Recorded::Key(key.action(), key.key_code()),
+                                InputEvent::MotionEvent(motion) => Recorded::Motion(
+                                    motion.action(),
+                                    motion.pointers().map(|pointer| (pointer.x(), pointer.y())).collect(),
+                                ),
+                                _ => return,
+                            };
+                            tx.send(recorded).unwrap();
+                        });
+                        return;
+                    }
+                    PollEvent::Main(main_event) => match main_event {
+                        MainEvent::Start => Recorded::Start,
+                        MainEvent::Resume { loader, .. } => Recorded::Resume(loader.load()),
+                        MainEvent::SaveState { saver, .. } => {
+                            saver.store(saved_state);
+                            Recorded::SaveState
+                        }
+                        MainEvent::Pause => Recorded::Pause,
+                        MainEvent::Stop => Recorded::Stop,
+                        MainEvent::Destroy => {
+                            destroyed = true;
+                            Recorded::Destroy
+                        }
+                        MainEvent::InitWindow { .. } => {
+                            let window = app.native_window().expect("No window during InitWindow");
+                            Recorded::InitWindow(window.width().unwrap(), window.height().unwrap())
+                        }
+                        MainEvent::TerminateWindow { .. } => Recorded::TerminateWindow,
+                        MainEvent::WindowResized { old_size, new_size, .. } => Recorded::WindowResized(old_size, new_size),
+                        MainEvent::RedrawNeeded { .. } => Recorded::RedrawNeeded,
+                        MainEvent::ContentRectChanged { rect, .. } => {
+                            assert_eq!(app.content_rect(), rect);
+                            Recorded::ContentRectChanged(rect)
+                        }
+                        MainEvent::GainedFocus => Recorded::GainedFocus,
+                        MainEvent::LostFocus => Recorded::LostFocus,
+                        MainEvent::ConfigChanged { diff, old, new, .. } => {
+                            assert_eq!(app.config(), new);
+                            Recorded::ConfigChanged(diff, old.orientation(), new.orientation())
+                        }
+                        MainEvent::LowMemory { level, .. } => Recorded::LowMemory(level),
+                        MainEvent::InsetsChanged { insets, .. } => Recorded::InsetsChanged(insets),
+                        _ => return,
+                    },
+                    _ => return,
+                };
+                tx.send(recorded).unwrap();
+            });
+        }
+    }
+}
+
+fn recv(rx: &Receiver<Recorded>) -> Recorded {
+    rx.recv_timeout(TIMEOUT).expect("Timed out waiting for application event")
+}
+
+#[test]
+fn lifecycle_events() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(b"", tx));
+
+    activity.start();
+    assert_eq!(recv(&rx), Recorded::Start);
+    activity.resume();
+    assert_eq!(recv(&rx), Recorded::Resume(None));
+    activity.pause();
+    assert_eq!(recv(&rx), Recorded::Pause);
+    activity.stop();
+    assert_eq!(recv(&rx), Recorded::Stop);
+
+    // Destroying the activity waits for the application to exit
+    activity.destroy();
+    assert_eq!(rx.try_recv(), Ok(Recorded::Destroy));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn lifecycle_state_tracking() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        tx.send((app.lifecycle_state(), app.is_visible(), app.is_focused(), app.has_window())).unwrap();
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| {
+                if let PollEvent::Main(main_event) = event {
+                    destroyed = matches!(main_event, MainEvent::Destroy);
+                    tx.send((app.lifecycle_state(), app.is_visible(), app.is_focused(), app.has_window())).unwrap();
+                }
+            });
+        }
+    });
+    let recv = || rx.recv_timeout(TIMEOUT).expect("Timed out waiting for application event");
+    assert_eq!(recv(), (LifecycleState::Created, false, false, false));
+
+    activity.start();
+    assert_eq!(recv(), (LifecycleState::Started, true, false, false));
+    activity.resume();
+    assert_eq!(recv(), (LifecycleState::Resumed, true, false, false));
+    activity.native_window_created(640, 480);
+    assert_eq!(recv(), (LifecycleState::Resumed, true, false, true));
+    activity.window_focus_changed(true);
+    assert_eq!(recv(), (LifecycleState::Resumed, true, true, true));
+    activity.window_focus_changed(false);
+    assert_eq!(recv(), (LifecycleState::Resumed, true, false, true));
+    activity.pause();
+    assert_eq!(recv(), (LifecycleState::Started, true, false, true));
+
+    // The window is still available while handling TerminateWindow
+    activity.native_window_destroyed();
+    assert_eq!(recv(), (LifecycleState::Started, true, false, true));
+    activity.stop();
+    assert_eq!(recv(), (LifecycleState::Created, false, false, false));
+
+    // Out of order transitions are still followed
+    activity.resume();
+    assert_eq!(recv(), (LifecycleState::Resumed, true, false, false));
+
+    activity.destroy();
+    assert_eq!(recv(), (LifecycleState::Destroyed, false, false, false));
+}
+
+#[test]
+fn saved_state_round_trip() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(b"hello", tx));
+    activity.start();
+    activity.resume();
+    activity.pause();
+    let saved_state = activity.save_instance_state();
+    activity.stop();
+    activity.destroy();
+    assert_eq!(saved_state.as_deref(), Some(&b"hello"[..]));
+    assert!(rx.iter().any(|event| event == Recorded::SaveState));
+
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(saved_state.as_deref(), recording_app(b"", tx));
+    activity.start();
+    activity.resume();
+    drop(activity);
+    assert_eq!(rx.iter().nth(1), Some(Recorded::Resume(Some(b"hello".to_vec()))));
+}
+
+// Larger than the default spill threshold
+static LARGE_STATE: [u8; 100 * 1024] = [0x5a; 100 * 1024];
+
+#[test]
+fn large_saved_state_is_spilled() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(&LARGE_STATE, tx));
+    activity.start();
+    activity.resume();
+    activity.pause();
+    let saved_state = activity.save_instance_state().expect("No saved state");
+    activity.stop();
+    activity.destroy();
+    drop(rx);
+
+    // Only a handle to the spilled file is passed to the Activity
+    assert!(saved_state.len() < 1024);
+    assert!(saved_state.starts_with(b"AASPILL\0"));
+    let name = std::str::from_utf8(&saved_state[20..]).unwrap();
+    let path = std::env::temp_dir().join(format!("android-activity-{}", std::process::id())).join("files").join(name);
+    assert!(path.exists());
+
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(Some(&saved_state), recording_app(b"", tx));
+    activity.start();
+    activity.resume();
+    drop(activity);
+    assert_eq!(rx.iter().nth(1), Some(Recorded::Resume(Some(LARGE_STATE.to_vec()))));
+    assert!(path.exists());
+
+    // The same state is restored again if the process dies before saving new
+    // state, and the file is only deleted once the next spill replaces it
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(Some(&saved_state), recording_app(&LARGE_STATE, tx));
+    activity.start();
+    activity.resume();
+    activity.pause();
+    let new_saved_state = activity.save_instance_state().expect("No saved state");
+    assert!(!path.exists());
+    activity.stop();
+    activity.destroy();
+    assert_eq!(rx.iter().nth(1), Some(Recorded::Resume(Some(LARGE_STATE.to_vec()))));
+
+    let new_name = std::str::from_utf8(&new_saved_state[20..]).unwrap();
+    assert_ne!(new_name, name);
+    let new_path = path.with_file_name(new_name);
+    assert!(new_path.exists());
+
+    // The file is also deleted once state is stored without spilling it
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(Some(&new_saved_state), recording_app(b"small", tx));
+    activity.start();
+    activity.resume();
+    activity.pause();
+    assert_eq!(activity.save_instance_state(), Some(b"small".to_vec()));
+    assert!(!new_path.exists());
+    activity.stop();
+    activity.destroy();
+    assert_eq!(rx.iter().nth(1), Some(Recorded::Resume(Some(LARGE_STATE.to_vec()))));
+}
+
+#[test]
+fn stale_spilled_state_is_deleted() {
+    // A data directory of its own, so that no other test's activity deletes the
+    // stale files
+    let data_dir = std::env::temp_dir().join(format!("android-activity-{}-stale-spills", std::process::id()));
+    let dir = data_dir.join("files");
+    std::fs::create_dir_all(&dir).unwrap();
+    let stale = dir.join("android-activity-saved-state-0-1");
+    let restored = dir.join("android-activity-saved-state-0-2");
+    let unrelated = dir.join("unrelated");
+    for path in [&stale, &restored, &unrelated] {
+        std::fs::write(path, b"state").unwrap();
+    }
+
+    // Only spilled state from other processes that isn't being restored is deleted
+    let mut handle = b"AASPILL\0".to_vec();
+    handle.extend_from_slice(&5u64.to_le_bytes());
+    handle.extend_from_slice(&0u32.to_le_bytes());
+    handle.extend_from_slice(b"android-activity-saved-state-0-2");
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create_with_data_dir(Some(&handle), &data_dir, recording_app(b"", tx));
+    activity.start();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(Recorded::Start));
+    assert!(!stale.exists());
+    assert!(restored.exists());
+    assert!(unrelated.exists());
+    activity.destroy();
+    let _ = std::fs::remove_dir_all(data_dir);
+}
+
+#[test]
+fn window_lifecycle() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(b"", tx));
+    activity.start();
+    activity.resume();
+
+    activity.native_window_created(640, 480);
+    assert_eq!(recv(&rx), Recorded::Start);
+    assert_eq!(recv(&rx), Recorded::Resume(None));
+    assert_eq!(recv(&rx), Recorded::InitWindow(640, 480));
+
+    activity.native_window_resized(800, 600);
+    activity.native_window_resized(1024, 768);
+    activity.native_window_redraw_needed();
+    activity.native_window_destroyed();
+
+    activity.destroy();
+    let events: Vec<Recorded> = rx.iter().collect();
+    assert_eq!(
+        events,
+        vec![
+            Recorded::WindowResized(Size { width: 640, height: 480 }, Size { width: 800, height: 600 }),
+            Recorded::WindowResized(Size { width: 800, height: 600 }, Size { width: 1024, height: 768 }),
+            Recorded::RedrawNeeded,
+            Recorded::TerminateWindow,
+            Recorded::Destroy,
+        ]
+    );
+}
+
+#[test]
+fn window_handles_are_invalidated() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        assert!(app.native_window().is_none());
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| match event {
+                PollEvent::Main(MainEvent::InitWindow { .. }) => tx.send(app.native_window().unwrap()).unwrap(),
+                PollEvent::Main(MainEvent::TerminateWindow { .. }) => {
+                    // The window stays valid for the duration of the callback
+                    let window = app.native_window().expect("No window during TerminateWindow");
+                    assert_eq!(window.width(), Ok(320));
+                }
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+            if destroyed {
+                assert!(app.native_window().is_none());
+            }
+        }
+    });
+
+    activity.native_window_created(320, 240);
+    let window: NativeWindowHandle = rx.recv_timeout(TIMEOUT).unwrap();
+
+    // Handles can be used from other threads, such as a render thread
+    let render_window = window.clone();
+    let size = std::thread::spawn(move || (render_window.width(), render_window.height())).join().unwrap();
+    assert_eq!(size, (Ok(320), Ok(240)));
+
+    // A handle that outlives the window reports an error instead of touching the window
+    activity.native_window_destroyed();
+    assert!(window.is_terminated());
+    assert_eq!(window.width(), Err(WindowTerminatedError));
+    assert_eq!(window.with_window(|_| ()), Err(WindowTerminatedError));
+
+    activity.destroy();
+}
+
+#[test]
+fn window_replaced() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(b"", tx));
+
+    // Creating a new surface terminates the old window before initializing the new one
+    activity.native_window_created(640, 480);
+    activity.native_window_created(800, 600);
+    activity.destroy();
+    let events: Vec<Recorded> = rx.iter().collect();
+    assert_eq!(
+        events,
+        vec![
+            Recorded::InitWindow(640, 480),
+            Recorded::TerminateWindow,
+            Recorded::InitWindow(800, 600),
+            Recorded::Destroy,
+        ]
+    );
+}
+
+#[test]
+fn render_thread_races_with_terminate_window() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let mut render_threads = vec![];
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| match event {
+                PollEvent::Main(MainEvent::InitWindow { .. }) => {
+                    let window = app.native_window().unwrap();
+                    render_threads.push(std::thread::spawn(move || {
+                        // The fake window reports a negative size if it's used
+                        // after the Java side has destroyed it
+                        while let Ok(width) = window.width() {
+                            assert_eq!(width, 64);
+                        }
+                    }));
+                }
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+        }
+        for thread in render_threads {
+            tx.send(thread.join().is_ok()).unwrap();
+        }
+    });
+
+    for _ in 0..50 {
+        activity.native_window_created(64, 64);
+        activity.native_window_destroyed();
+    }
+    activity.destroy();
+    let results: Vec<bool> = rx.iter().collect();
+    assert_eq!(results, vec![true; 50]);
+}
+
+#[test]
+fn early_return_does_not_block_activity() {
+    let activity = TestActivity::create(None, |_app| {});
+
+    // None of the callbacks that wait for the application can block once
+    // `main` has returned
+    activity.start();
+    activity.resume();
+    activity.native_window_created(640, 480);
+    assert_eq!(activity.save_instance_state(), None);
+    activity.native_window_destroyed();
+    activity.pause();
+    assert!(activity.is_finished());
+    activity.destroy();
+}
+
+#[test]
+fn notifications() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(b"", tx));
+
+    activity.window_focus_changed(true);
+    assert_eq!(recv(&rx), Recorded::GainedFocus);
+    activity.window_focus_changed(false);
+    assert_eq!(recv(&rx), Recorded::LostFocus);
+
+    let rect = Rect { left: 0, top: 10, right: 100, bottom: 200 };
+    activity.content_rect_changed(rect.clone());
+    assert_eq!(recv(&rx), Recorded::ContentRectChanged(rect));
+
+    activity.configuration_changed(|config| config.orientation = ndk_sys::ACONFIGURATION_ORIENTATION_LAND as i32);
+    assert_eq!(recv(&rx), Recorded::ConfigChanged(ConfigurationDiff::ORIENTATION, Orientation::Any, Orientation::Land));
+    activity.configuration_changed(|config| {
+        config.language = *b"fr";
+        config.density = ndk_sys::ACONFIGURATION_DENSITY_XHIGH as i32;
+    });
+    assert_eq!(
+        recv(&rx),
+        Recorded::ConfigChanged(ConfigurationDiff::LOCALE | ConfigurationDiff::DENSITY, Orientation::Land, Orientation::Land)
+    );
+
+    activity.low_memory();
+    assert_eq!(recv(&rx), Recorded::LowMemory(TrimMemoryLevel::Complete));
+    activity.trim_memory(TrimMemoryLevel::UiHidden);
+    assert_eq!(recv(&rx), Recorded::LowMemory(TrimMemoryLevel::UiHidden));
+
+    let status_bar = Rect { left: 0, top: 24, right: 0, bottom: 0 };
+    activity.window_insets_changed(&[(InsetType::StatusBars, status_bar.clone())]);
+    match recv(&rx) {
+        Recorded::InsetsChanged(insets) => {
+            assert_eq!(insets.get(InsetType::StatusBars), &status_bar);
+            assert_eq!(insets.get(InsetType::Ime), &Rect::default());
+        }
+        event => panic!("Unexpected event {event:?}"),
+    }
+}
+
+#[test]
+fn buffered_input_and_waker() {
+    let (tx, rx) = mpsc::channel();
+    let (app_tx, app_rx) = mpsc::channel();
+    let record = recording_app(b"", tx);
+    let activity = TestActivity::create(None, move |app| {
+        app_tx.send(app.create_waker()).unwrap();
+        record(app)
+    });
+    let waker = app_rx.recv().unwrap();
+
+    activity.key_event(KeyAction::Down, Keycode::A);
+    activity.motion_event(MotionAction::Down, &[(1.0, 2.0), (3.0, 4.0)]);
+
+    // Input doesn't wake the application by itself
+    assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+
+    waker.wake();
+    assert_eq!(recv(&rx), Recorded::Wake);
+    assert_eq!(recv(&rx), Recorded::Key(KeyAction::Down, Keycode::A));
+    assert_eq!(recv(&rx), Recorded::Motion(MotionAction::Down, vec![(1.0, 2.0), (3.0, 4.0)]));
+
+    // The input buffer was consumed
+    waker.wake();
+    assert_eq!(recv(&rx), Recorded::Wake);
+    assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn input_events_are_delivered_in_order() {
+    let (tx, rx) = mpsc::channel();
+    let (app_tx, app_rx) = mpsc::channel();
+    let record = recording_app(b"", tx);
+    let activity = TestActivity::create(None, move |app| {
+        app_tx.send(app.create_waker()).unwrap();
+        record(app)
+    });
+    let waker = app_rx.recv().unwrap();
+
+    activity.motion_event(MotionAction::Down, &[(1.0, 2.0)]);
+    activity.key_event(KeyAction::Down, Keycode::A);
+    activity.motion_event(MotionAction::Up, &[(1.0, 2.0)]);
+    activity.key_event(KeyAction::Up, Keycode::A);
+
+    waker.wake();
+    assert_eq!(recv(&rx), Recorded::Wake);
+    assert_eq!(recv(&rx), Recorded::Motion(MotionAction::Down, vec![(1.0, 2.0)]));
+    assert_eq!(recv(&rx), Recorded::Key(KeyAction::Down, Keycode::A));
+    assert_eq!(recv(&rx), Recorded::Motion(MotionAction::Up, vec![(1.0, 2.0)]));
+    assert_eq!(recv(&rx), Recorded::Key(KeyAction::Up, Keycode::A));
+    activity.destroy();
+}
+
+#[test]
+fn input_queue_wakes_application() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, recording_app(b"", tx));
+
+    activity.input_queue_created();
+    activity.key_event(KeyAction::Down, Keycode::A);
+    assert_eq!(recv(&rx), Recorded::Wake);
+    assert_eq!(recv(&rx), Recorded::Key(KeyAction::Down, Keycode::A));
+
+    // Each new input wakes the application once
+    activity.motion_event(MotionAction::Down, &[(1.0, 2.0)]);
+    assert_eq!(recv(&rx), Recorded::Wake);
+    assert_eq!(recv(&rx), Recorded::Motion(MotionAction::Down, vec![(1.0, 2.0)]));
+    assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+
+    activity.input_queue_destroyed();
+    activity.key_event(KeyAction::Up, Keycode::A);
+    assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn input_buffer_grows_and_reports_overflow() {
+    let (tx, rx) = mpsc::channel();
+    let (app_tx, app_rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        app_tx.send(app.create_waker()).unwrap();
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(None, |event| match event {
+                PollEvent::Wake => {
+                    let mut count = 0;
+                    let overflowed = app.input_events(|_| count += 1);
+                    app.set_max_buffered_input_events(10);
+                    tx.send((count, overflowed)).unwrap();
+                }
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+        }
+    });
+    let waker = app_rx.recv().unwrap();
+
+    // Far more events than fit in the fixed size buffers of android_native_app_glue
+    for _ in 0..100 {
+        activity.key_event(KeyAction::Down, Keycode::A);
+        activity.key_event(KeyAction::Up, Keycode::A);
+    }
+    waker.wake();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok((200, 0)));
+
+    // Events beyond the limit are dropped and counted
+    for _ in 0..15 {
+        activity.motion_event(MotionAction::Move, &[(1.0, 2.0)]);
+    }
+    waker.wake();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok((10, 5)));
+
+    waker.wake();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok((0, 0)));
+}
+
+#[test]
+fn android_app_getter() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |_app| {
+        tx.send(android_app().is_ok()).unwrap();
+    });
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(true));
+    activity.destroy();
+
+    // Only the thread running the application's main function has an app
+    assert_eq!(android_app().err(), Some(AndroidAppError::NotRunning));
+}
+
+#[test]
+fn finish_still_delivers_destroy() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(Some(TIMEOUT), |event| match event {
+                PollEvent::Main(MainEvent::Start) => app.finish(),
+                PollEvent::Main(MainEvent::Destroy) => destroyed = true,
+                _ => {}
+            });
+        }
+        tx.send(android_app().is_ok()).unwrap();
+    });
+    assert!(!activity.is_finishing());
+    activity.start();
+    while !activity.is_finishing() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    activity.stop();
+    assert!(!activity.is_finished());
+    activity.destroy();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(true));
+}
+
+#[test]
+fn finish_from_another_thread() {
+    let (tx, rx) = mpsc::channel();
+    let (destroyed_tx, destroyed_rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        tx.send(app.create_finisher()).unwrap();
+        let mut destroyed = false;
+        while !destroyed {
+            app.poll_events(Some(TIMEOUT), |event| destroyed = matches!(event, PollEvent::Main(MainEvent::Destroy)));
+        }
+        destroyed_tx.send(()).unwrap();
+    });
+    let finisher = rx.recv_timeout(TIMEOUT).unwrap();
+    assert!(!activity.is_finishing());
+    std::thread::spawn(move || finisher.finish()).join().unwrap();
+    assert!(activity.is_finishing());
+
+    // The application still sees the rest of its lifecycle
+    activity.destroy();
+    assert_eq!(destroyed_rx.recv_timeout(TIMEOUT), Ok(()));
+}
+
+#[test]
+fn returning_from_main_finishes_the_activity() {
+    let activity = TestActivity::create(None, |_app| {});
+    while !activity.is_finished() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert!(activity.is_finishing());
+    let (saved_state, activity) = lifecycle_after_main_returns(activity).recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(saved_state, None);
+    activity.destroy();
+}
+
+#[test]
+#[should_panic(expected = "application panic")]
+fn panicking_main_finishes_the_activity() {
+    let activity = TestActivity::create(None, |_app| panic!("application panic"));
+    while !activity.is_finished() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert!(activity.is_finishing());
+    let (saved_state, activity) = lifecycle_after_main_returns(activity).recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(saved_state, None);
+    activity.destroy();
+}
+
+// Runs the Activity's remaining lifecycle callbacks on another thread, like the
+// Java main thread would while the Activity finishes, and reports the saved
+// state once they have all returned. None of them may block, since there's no
+// longer an application thread to acknowledge them.
+fn lifecycle_after_main_returns(activity: TestActivity) -> Receiver<(Option<Vec<u8>>, TestActivity)> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        activity.start();
+        activity.resume();
+        activity.native_window_created(640, 480);
+        activity.pause();
+        activity.native_window_destroyed();
+        activity.stop();
+        let saved_state = activity.save_instance_state();
+        let _ = tx.send((saved_state, activity));
+    });
+    rx
+}
+
+#[test]
+fn storage_paths_and_sdk_version() {
+    let (tx, rx) = mpsc::channel();
+    let config = TestConfiguration { sdk_version: 33, ..Default::default() };
+    let activity = TestActivity::create_with_config(None, config, move |app| {
+        tx.send((app.internal_data_path(), app.external_data_path(), app.obb_path(), app.sdk_version())).unwrap();
+    });
+    let (internal, external, obb, sdk_version) = rx.recv_timeout(TIMEOUT).unwrap();
+    activity.destroy();
+
+    let (internal, external, obb) = (internal.unwrap(), external.unwrap(), obb.unwrap());
+    assert!(internal.starts_with(std::env::temp_dir()));
+    assert_ne!(internal, external);
+    assert_ne!(internal, obb);
+    assert_eq!(sdk_version, 33);
+}
+
+#[test]
+fn timeout() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        app.poll_events(Some(Duration::from_millis(10)), |event| {
+            tx.send(matches!(event, PollEvent::Timeout)).unwrap();
+        });
+    });
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(true));
+    activity.destroy();
+}
+
+#[test]
+#[should_panic(expected = "application panic")]
+fn application_panics_are_propagated() {
+    let activity = TestActivity::create(None, |_app| panic!("application panic"));
+    activity.destroy();
+}
+
+#[test]
+fn registered_fds_are_polled() {
+    let (tx, rx) = mpsc::channel();
+    let (mut writer, reader) = UnixStream::pair().unwrap();
+    let activity = TestActivity::create(None, move |app| {
+        let mut reader = reader;
+        let token = unsafe { app.register_fd(reader.as_raw_fd(), FdEvent::INPUT) }.unwrap();
+        tx.send(None).unwrap();
+
+        let mut received = None;
+        while received.is_none() {
+            app.poll_events(Some(TIMEOUT), |event| {
+                if let PollEvent::Fd { token: event_token, events, .. } = event {
+                    assert_eq!(event_token, token);
+                    assert!(events.contains(FdEvent::INPUT));
+                    let mut buf = [0u8; 5];
+                    reader.read_exact(&mut buf).unwrap();
+                    received = Some(buf);
+                }
+            });
+        }
+        tx.send(received).unwrap();
+
+        // Nothing is delivered for the fd once it's unregistered
+        assert!(app.unregister_fd(token));
+        assert!(!app.unregister_fd(token));
+        tx.send(None).unwrap();
+        app.poll_events(Some(Duration::from_millis(100)), |event| {
+            assert!(!matches!(event, PollEvent::Fd { .. }), "Unexpected event {event:?}");
+        });
+    });
+
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(None));
+    writer.write_all(b"hello").unwrap();
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(Some(*b"hello")));
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(None));
+    writer.write_all(b"ignored").unwrap();
+    activity.destroy();
+}
+
+#[test]
+fn timers() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let once = app.add_timer(Duration::from_millis(50), false).unwrap();
+        let repeating = app.add_timer(Duration::from_millis(10), true).unwrap();
+
+        let mut once_count = 0;
+        let mut repeating_count = 0;
+        while once_count == 0 {
+            app.poll_events(Some(TIMEOUT), |event| match event {
+                PollEvent::Timer(id) if id == once => once_count += 1,
+                PollEvent::Timer(id) if id == repeating => repeating_count += 1,
+                _ => {}
+            });
+        }
+        assert!(repeating_count >= 2, "Repeating timer only fired {repeating_count} times");
+
+        // Cancelled timers are no longer delivered, while expired one-shot timers
+        // can be rescheduled
+        assert!(app.cancel_timer(repeating));
+        assert!(!app.cancel_timer(repeating));
+        assert_eq!(app.reschedule_timer(repeating, Duration::ZERO, false).unwrap_err().kind(), std::io::ErrorKind::NotFound);
+        app.reschedule_timer(once, Duration::ZERO, false).unwrap();
+
+        let mut fired = vec![];
+        while fired.is_empty() {
+            app.poll_events(Some(TIMEOUT), |event| {
+                if let PollEvent::Timer(id) = event {
+                    fired.push(id);
+                }
+            });
+        }
+        app.poll_events(Some(Duration::from_millis(50)), |event| {
+            if let PollEvent::Timer(id) = event {
+                fired.push(id);
+            }
+        });
+        tx.send(fired == [once]).unwrap();
+    });
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok(true));
+    activity.destroy();
+}
+
+#[test]
+fn user_events_are_delivered_in_order() {
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let numbers = app.create_event_channel::<u32>().unwrap();
+        let names = app.create_event_channel::<&'static str>().unwrap();
+        tx.send((numbers.clone(), names)).unwrap();
+
+        let mut received = vec![];
+        while received.len() < 3 {
+            app.poll_events(Some(TIMEOUT), |event| {
+                if let PollEvent::User(event) = event {
+                    let event = match event.downcast::<u32>() {
+                        Ok(number) => number.to_string(),
+                        Err(event) => event.downcast::<&'static str>().unwrap().to_string(),
+                    };
+                    received.push(event);
+                }
+            });
+        }
+        assert_eq!(received, ["1", "two", "3"]);
+    });
+
+    let (numbers, names) = rx.recv_timeout(TIMEOUT).unwrap();
+    let late = numbers.clone();
+    std::thread::spawn(move || {
+        numbers.send(1).unwrap();
+        names.send("two").unwrap();
+        numbers.send(3).unwrap();
+    })
+    .join()
+    .unwrap();
+    activity.destroy();
+
+    // Events can't be sent once the application's main loop has finished
+    assert_eq!(late.send(4).unwrap_err().0, 4);
+}
+
+#[test]
+fn frames() {
+    const VSYNC_PERIOD_NANOS: i64 = 16_666_667;
+
+    let (tx, rx) = mpsc::channel();
+    let activity = TestActivity::create(None, move |app| {
+        let poll_frames = |timeout| {
+            let mut frames = vec![];
+            app.poll_events(Some(timeout), |event| {
+                if let PollEvent::Frame { frame_time_nanos, vsync_id, .. } = event {
+                    frames.push((frame_time_nanos, vsync_id));
+                }
+            });
+            frames
+        };
+
+        // Requests for the same frame are coalesced
+        app.request_frame().unwrap();
+        app.request_frame().unwrap();
+        let mut frames = vec![];
+        while frames.is_empty() {
+            frames = poll_frames(TIMEOUT);
+        }
+        frames.extend(poll_frames(Duration::from_millis(50)));
+        assert_eq!(frames.len(), 1);
+        let (frame_time_nanos, vsync_id) = frames[0];
+        assert_eq!(frame_time_nanos % VSYNC_PERIOD_NANOS, 0);
+        assert_eq!(vsync_id, None);
+
+        // Each request is for a single frame, which can be cancelled
+        app.request_frame().unwrap();
+        app.cancel_frame();
+        let cancelled = poll_frames(Duration::from_millis(50));
+
+        app.request_frame().unwrap();
+        let mut next = vec![];
+        while next.is_empty() {
+            next = poll_frames(TIMEOUT);
+        }
+        tx.send((cancelled.is_empty(), next[0].0 > frame_time_nanos)).unwrap();
+    });
+    assert_eq!(rx.recv_timeout(TIMEOUT), Ok((true, true)));
+    activity.destroy();
+}
+
+#[test]
+fn trace_is_a_no_op_on_host() {
+    use android_activity::trace;
+
+    assert!(!trace::is_enabled());
+    {
+        let _section = trace::section("section");
+        trace::begin_section("nested");
+        trace::end_section();
+    }
+    trace::begin_async_section("async", 1);
+    trace::end_async_section("async", 1);
+    trace::set_counter("counter", 42);
+}
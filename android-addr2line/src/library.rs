@@ -0,0 +1,103 @@
+//! Locating the unstripped libraries that match a backtrace's frames
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use object::{Object, ObjectSection};
+
+/// A library (or executable) that was found within a search directory
+#[derive(Debug)]
+pub struct Library {
+    pub path: PathBuf,
+
+    /// The GNU build ID (lower case hex)
+    pub build_id: Option<String>,
+
+    /// Whether the library has DWARF debug info, rather than just a symbol table
+    pub has_debug_info: bool,
+
+    pub modified: Option<SystemTime>,
+}
+
+impl Library {
+    fn load(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let modified = file.metadata().and_then(|metadata| metadata.modified()).ok();
+        let data = unsafe { memmap2::Mmap::map(&file)? };
+        let object = object::File::parse(&*data).map_err(io::Error::other)?;
+
+        let build_id = object.build_id().map_err(io::Error::other)?.map(|id| {
+            id.iter().fold(String::with_capacity(id.len() * 2), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+        });
+        let has_debug_info = object.section_by_name(".debug_info").is_some_and(|section| section.size() > 0);
+        Ok(Self { path: path.to_path_buf(), build_id, has_debug_info, modified })
+    }
+}
+
+/// The libraries within a set of search directories (normally Cargo target
+/// directories) that have one of the names that we're looking for
+#[derive(Debug, Default)]
+pub struct Libraries {
+    libraries: Vec<(String, Library)>,
+}
+
+impl Libraries {
+    /// Recursively searches `dirs` for files called any of `names`
+    ///
+    /// Symbolic links to directories aren't followed, and files that aren't
+    /// object files are ignored.
+    pub fn find(dirs: &[PathBuf], names: &HashSet<&str>) -> Self {
+        let mut libraries = Self::default();
+        let mut pending = dirs.to_vec();
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else { continue };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else { continue };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                let Some(name) = entry.file_name().to_str().filter(|name| names.contains(name)).map(str::to_string) else {
+                    continue;
+                };
+                if let Ok(library) = Library::load(&path) {
+                    libraries.libraries.push((name, library));
+                }
+            }
+        }
+        libraries
+    }
+
+    /// Returns the best library with the given name and build ID
+    ///
+    /// Without a build ID, the most recently built library with debug info is
+    /// returned. Libraries with debug info are preferred over (stripped) copies of
+    /// the same build.
+    pub fn lookup(&self, name: &str, build_id: Option<&str>) -> Option<&Library> {
+        let candidates = self.libraries.iter()
+            .filter(|(library_name, _)| library_name == name)
+            .map(|(_, library)| library);
+        match build_id {
+            Some(build_id) => candidates
+                .filter(|library| library.build_id.as_deref().is_some_and(|id| id.eq_ignore_ascii_case(build_id)))
+                .max_by_key(|library| library.has_debug_info),
+            None => candidates
+                .filter(|library| library.has_debug_info)
+                .max_by_key(|library| library.modified),
+        }
+    }
+
+    /// Returns whether any library with the given name was found, regardless of
+    /// its build ID
+    pub fn contains(&self, name: &str) -> bool {
+        self.libraries.iter().any(|(library_name, _)| library_name == name)
+    }
+}
@@ -0,0 +1,204 @@
+//! Symbolizes the backtraces in Android tombstones and logcat dumps
+//!
+//! ```text
+//! adb logcat -d | android-addr2line
+//! android-addr2line --target-dir path/to/target tombstone_00
+//! ```
+//!
+//! Every line of the input is printed, and each backtrace frame within a library
+//! that can be found in the Cargo target directory (e.g. `libmain.so`) is
+//! followed by its function names and source locations, including any inlined
+//! functions, with Rust (and C++) names demangled.
+//!
+//! Frames are only matched to a library with the same GNU build ID, when the
+//! tombstone includes one, so that a stale build can't be used by mistake. The
+//! Android build tools strip the libraries that are packaged within an APK, so
+//! the unstripped copies in the target directory are used for their debug info.
+
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use addr2line::Loader;
+
+mod library;
+mod tombstone;
+
+use library::Libraries;
+use tombstone::{parse_frame, Frame};
+
+const USAGE: &str = "\
+Usage: android-addr2line [OPTIONS] [FILE]
+
+Symbolizes the backtraces in a tombstone or logcat dump that's read from FILE,
+or from stdin if FILE is missing or '-'.
+
+Options:
+  -d, --target-dir DIR  Searches DIR for unstripped libraries (may be repeated;
+                        defaults to $CARGO_TARGET_DIR or ./target)
+  -h, --help            Prints this help
+";
+
+#[derive(Debug, Default)]
+struct Args {
+    target_dirs: Vec<PathBuf>,
+    input: Option<PathBuf>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-d" | "--target-dir" => {
+                let dir = args.next().ok_or_else(|| format!("Missing directory after {arg}"))?;
+                parsed.target_dirs.push(dir.into());
+            }
+            "-" if parsed.input.is_none() => parsed.input = Some(arg.into()),
+            _ if arg.starts_with('-') => return Err(format!("Unknown option {arg}")),
+            _ if parsed.input.is_none() => parsed.input = Some(arg.into()),
+            _ => return Err(format!("Unexpected argument {arg}")),
+        }
+    }
+    if parsed.target_dirs.is_empty() {
+        parsed.target_dirs.push(env::var_os("CARGO_TARGET_DIR").map_or_else(|| "target".into(), PathBuf::from));
+    }
+    Ok(Some(parsed))
+}
+
+fn read_input(input: Option<&Path>) -> io::Result<String> {
+    let bytes = match input {
+        Some(path) if path != Path::new("-") => fs::read(path)?,
+        _ => {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes)?;
+            bytes
+        }
+    };
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Symbolizes frames, loading the debug info for each library once
+struct Symbolizer {
+    libraries: Libraries,
+    loaders: HashMap<PathBuf, Option<Loader>>,
+
+    // Libraries that have already been warned about, by name and build ID
+    warned: HashSet<(String, Option<String>)>,
+}
+
+impl Symbolizer {
+    fn warn_once(&mut self, frame: &Frame<'_>, warning: impl FnOnce() -> String) {
+        if self.warned.insert((frame.library_name().to_string(), frame.build_id.map(str::to_string))) {
+            eprintln!("android-addr2line: {}", warning());
+        }
+    }
+
+    /// Writes the functions (innermost first) and source locations for `frame`
+    fn symbolize(&mut self, frame: &Frame<'_>, out: &mut impl Write) -> io::Result<()> {
+        let name = frame.library_name();
+        let Some(path) = self.libraries.lookup(name, frame.build_id).map(|library| library.path.clone()) else {
+            if self.libraries.contains(name) {
+                let build_id = frame.build_id.unwrap_or_default();
+                self.warn_once(frame, || format!("No build of {name} with BuildId {build_id} was found"));
+            }
+            return Ok(());
+        };
+        if frame.build_id.is_none() {
+            self.warn_once(frame, || format!("No BuildId for {name}, so using {}", path.display()));
+        }
+
+        let loader = self.loaders.entry(path).or_insert_with_key(|path| match Loader::new(path) {
+            Ok(loader) => Some(loader),
+            Err(err) => {
+                eprintln!("android-addr2line: Failed to load debug info from {}: {err}", path.display());
+                None
+            }
+        });
+        let Some(loader) = loader else { return Ok(()) };
+
+        let indent = " ".repeat(frame.indent + 4);
+        let mut lines = Vec::new();
+        if let Ok(mut frames) = loader.find_frames(frame.pc) {
+            while let Ok(Some(found)) = frames.next() {
+                let function = found.function.as_ref().and_then(|function| function.demangle().ok());
+                let location = found.location.as_ref().and_then(|location| {
+                    let file = location.file?;
+                    Some(match (location.line, location.column) {
+                        (Some(line), Some(column)) if column > 0 => format!("{file}:{line}:{column}"),
+                        (Some(line), _) => format!("{file}:{line}"),
+                        (None, _) => file.to_string(),
+                    })
+                });
+                lines.push((function.map(|function| function.into_owned()), location));
+            }
+        }
+        if lines.iter().all(|(function, _)| function.is_none()) {
+            // No debug info for this address, so fall back to the symbol table
+            if let Some(symbol) = loader.find_symbol(frame.pc) {
+                let symbol = addr2line::demangle_auto(symbol.into(), None).into_owned();
+                let location = lines.first().and_then(|(_, location)| location.clone());
+                lines = vec![(Some(symbol), location)];
+            }
+        }
+
+        let inlined = lines.len().saturating_sub(1);
+        for (i, (function, location)) in lines.into_iter().enumerate() {
+            let function = function.as_deref().unwrap_or("??");
+            let suffix = if i < inlined { " [inlined]" } else { "" };
+            writeln!(out, "{indent}{function}{suffix}")?;
+            if let Some(location) = location {
+                writeln!(out, "{indent}    at {location}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let input = read_input(args.input.as_deref())?;
+    let names: HashSet<&str> = input.lines()
+        .filter_map(parse_frame)
+        .map(|frame| frame.library_name())
+        .collect();
+    let mut symbolizer = Symbolizer {
+        libraries: Libraries::find(&args.target_dirs, &names),
+        loaders: HashMap::new(),
+        warned: HashSet::new(),
+    };
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    for line in input.lines() {
+        writeln!(out, "{line}")?;
+        if let Some(frame) = parse_frame(line) {
+            symbolizer.symbolize(&frame, &mut out)?;
+        }
+    }
+    out.flush()
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprint!("android-addr2line: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("android-addr2line: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
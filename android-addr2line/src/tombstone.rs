@@ -0,0 +1,61 @@
+//! Parsing of backtrace frames from tombstones and logcat dumps
+
+/// A backtrace frame, such as
+/// `#01 pc 000000000004e8f0  /data/app/~~x==/com.example-y==/lib/arm64/libmain.so (android_main+120) (BuildId: 5e3f...)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame<'a> {
+    /// The offset of the `#` within the line, for indenting the symbolized frames
+    pub indent: usize,
+
+    /// The address relative to the start of the library (i.e. as a virtual address
+    /// within the ELF file)
+    pub pc: u64,
+
+    /// The library's path on the device
+    pub path: &'a str,
+
+    /// The library's GNU build ID (lower case hex), if it was printed
+    pub build_id: Option<&'a str>,
+}
+
+impl<'a> Frame<'a> {
+    /// The library's file name, which is also the name of the library within an
+    /// APK for paths like `/data/app/.../base.apk!libmain.so`
+    pub fn library_name(&self) -> &'a str {
+        self.path.rsplit(['/', '!']).next().unwrap_or(self.path)
+    }
+}
+
+// Splits `s` after its leading characters that match `pred`
+fn split_while(s: &str, pred: impl Fn(char) -> bool) -> (&str, &str) {
+    s.split_at(s.find(|c| !pred(c)).unwrap_or(s.len()))
+}
+
+/// Parses a backtrace frame from a line of a tombstone, which may be prefixed
+/// by logcat's timestamp, priority and tag
+pub fn parse_frame(line: &str) -> Option<Frame<'_>> {
+    let indent = line.find('#')?;
+    let (number, rest) = split_while(&line[indent + 1..], |c| c.is_ascii_digit());
+    if number.is_empty() {
+        return None;
+    }
+    let rest = rest.trim_start().strip_prefix("pc")?;
+    let (pc, rest) = split_while(rest.trim_start(), |c| c.is_ascii_hexdigit());
+    let (path, rest) = split_while(rest.trim_start(), |c| !c.is_whitespace());
+    if path.is_empty() {
+        return None;
+    }
+
+    let build_id = rest.find("(BuildId: ").and_then(|start| {
+        let id = &rest[start + "(BuildId: ".len()..];
+        let id = &id[..id.find(')')?];
+        (!id.is_empty() && id.chars().all(|c| c.is_ascii_hexdigit())).then_some(id)
+    });
+
+    Some(Frame {
+        indent,
+        pc: u64::from_str_radix(pc, 16).ok()?,
+        path,
+        build_id,
+    })
+}
@@ -0,0 +1,80 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use object::{Object, ObjectSymbol};
+
+const BIN: &str = env!("CARGO_BIN_EXE_android-addr2line");
+
+// Copies the (unstripped) `android-addr2line` executable into a new target
+// directory as `libmain.so`, as a stand-in for a library that was built for
+// Android, and returns the directory along with the executable's build ID and
+// the address of `parse_frame()`
+fn fake_target_dir(test: &str) -> (PathBuf, String, u64) {
+    let dir = std::env::temp_dir().join(format!("android-addr2line-{test}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let lib_dir = dir.join("aarch64-linux-android/debug");
+    fs::create_dir_all(&lib_dir).unwrap();
+    fs::copy(BIN, lib_dir.join("libmain.so")).unwrap();
+
+    let data = fs::read(BIN).unwrap();
+    let object = object::File::parse(&*data).unwrap();
+    let build_id = object.build_id().unwrap().expect("test executable has no build ID");
+    let build_id: String = build_id.iter().map(|byte| format!("{byte:02x}")).collect();
+    let address = object.symbols()
+        .find(|symbol| {
+            symbol.name().is_ok_and(|name| {
+                format!("{:#}", rustc_demangle::demangle(name)) == "android_addr2line::tombstone::parse_frame"
+            })
+        })
+        .expect("parse_frame symbol not found")
+        .address();
+    (dir, build_id, address)
+}
+
+fn symbolize(target_dir: &PathBuf, input: &str) -> Output {
+    let mut child = Command::new(BIN)
+        .arg("--target-dir").arg(target_dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    output
+}
+
+#[test]
+fn symbolizes_frames_with_matching_build_id() {
+    let (dir, build_id, address) = fake_target_dir("matching");
+    let input = format!(
+        "10-17 12:00:00.000  1234  1234 F DEBUG   : backtrace:\n\
+         10-17 12:00:00.000  1234  1234 F DEBUG   :       #00 pc {address:016x}  /data/app/~~a==/com.example-b==/lib/arm64/libmain.so (BuildId: {build_id})\n\
+         10-17 12:00:00.000  1234  1234 F DEBUG   :       #01 pc 000000000004e8f0  /apex/com.android.runtime/lib64/bionic/libc.so (abort+164) (BuildId: 0123)\n"
+    );
+    let output = symbolize(&dir, &input);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    // The input is passed through, with the frame's symbols following it
+    assert_eq!(lines[0..2], input.lines().take(2).collect::<Vec<_>>()[..]);
+    assert!(lines[2].trim_start().starts_with("android_addr2line::tombstone::parse_frame"), "{stdout}");
+    assert!(lines[3].contains("src/tombstone.rs:") || lines[3].contains("src\\tombstone.rs:"), "{stdout}");
+    assert_eq!(lines[4..], input.lines().skip(2).collect::<Vec<_>>()[..]);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn ignores_frames_with_other_build_id() {
+    let (dir, _, address) = fake_target_dir("other");
+    let input = format!("    #00 pc {address:x}  /data/app/base.apk!libmain.so (offset 0x1000) (BuildId: 00112233)\n");
+    let output = symbolize(&dir, &input);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), input);
+    assert!(String::from_utf8(output.stderr).unwrap().contains("No build of libmain.so with BuildId 00112233"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
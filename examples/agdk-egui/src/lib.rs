@@ -149,8 +149,10 @@ fn _main() {
 
 
 #[cfg(target_os="android")]
-#[no_mangle]
-extern "C" fn android_main() {
+android_activity::main!(android_main);
+
+#[cfg(target_os="android")]
+fn android_main(_app: android_activity::AndroidApp) {
     android_logger::init_once(
         android_logger::Config::default().with_min_level(Level::Trace)
     );
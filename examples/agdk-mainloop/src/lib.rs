@@ -1,7 +1,7 @@
 
-use game_activity::{PollEvent, MainEvent};
-use log::Level;
-use log::trace;
+use android_activity::{logger, AndroidApp, PollEvent, MainEvent};
+use log::LevelFilter;
+use log::{trace, warn};
 use std::time::Duration;
 use serde::{Serialize, Deserialize};
 
@@ -11,18 +11,20 @@ struct AppState {
     uri: String,
 }
 
-#[no_mangle]
-extern "C" fn android_main() {
+// Bumped whenever `AppState` changes, so that state saved by an older build is discarded
+const STATE_VERSION: u32 = 1;
 
-    android_logger::init_once(
-        android_logger::Config::default().with_min_level(Level::Trace)
-    );
+android_activity::main!(android_main);
+
+fn android_main(app: AndroidApp) {
+
+    // The logcat logger is installed by the glue layer (via the "logger" feature)
+    logger::set_max_level(LevelFilter::Trace);
 
     let mut quit = false;
     let mut redraw_pending = true;
     let mut render_state: Option<()> = Default::default();
 
-    let app = game_activity::android_app();
     while !quit {
         app.poll_events(Some(Duration::from_millis(500)) /* timeout */, |event| {
             match event {
@@ -36,13 +38,12 @@ extern "C" fn android_main() {
                     trace!("Main event: {:?}", main_event);
                     match main_event {
                         MainEvent::SaveState { saver, .. } => {
-                            let state = serde_json::to_vec(&AppState { uri: format!("foo://bar") }).unwrap();
-                            saver.store(&state);
+                            saver.store_serialized(STATE_VERSION, &AppState { uri: format!("foo://bar") }).unwrap();
                         },
                         MainEvent::Pause => {},
                         MainEvent::Resume { loader, .. } => {
-                            if let Some(state) = loader.load() {
-                                let _state: AppState = serde_json::from_slice(&state).unwrap();
+                            if let Err(err) = loader.load_deserialized::<AppState>(STATE_VERSION) {
+                                warn!("Discarding saved state: {err}");
                             }
                         },
                         MainEvent::InitWindow { .. } => {
@@ -54,7 +55,7 @@ extern "C" fn android_main() {
                         }
                         MainEvent::WindowResized { .. } => { redraw_pending = true; },
                         MainEvent::RedrawNeeded { ..} => { redraw_pending = true; },
-                        MainEvent::LowMemory => {},
+                        MainEvent::LowMemory { .. } => {},
 
                         MainEvent::Destroy => { quit = true },
                         _ => { /* ... */}
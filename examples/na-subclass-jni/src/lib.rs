@@ -1,7 +1,7 @@
 
-use native_activity::{PollEvent, MainEvent};
+use android_activity::{AndroidApp, PollEvent, MainEvent};
 use log::Level;
-use log::{trace, info};
+use log::{trace, info, warn};
 use std::time::Duration;
 use serde::{Serialize, Deserialize};
 
@@ -10,8 +10,12 @@ struct AppState {
     uri: String,
 }
 
-#[no_mangle]
-extern "C" fn android_main() {
+// Bumped whenever `AppState` changes, so that state saved by an older build is discarded
+const STATE_VERSION: u32 = 1;
+
+android_activity::main!(android_main);
+
+fn android_main(app: AndroidApp) {
 
     android_logger::init_once(
         android_logger::Config::default().with_min_level(Level::Info)
@@ -21,7 +25,6 @@ extern "C" fn android_main() {
     let mut redraw_pending = true;
     let mut render_state: Option<()> = Default::default();
 
-    let app = native_activity::android_app();
     while !quit {
         app.poll_events(Some(Duration::from_millis(500)) /* timeout */, |event| {
             match event {
@@ -35,14 +38,14 @@ extern "C" fn android_main() {
                     info!("Main event: {:?}", main_event);
                     match main_event {
                         MainEvent::SaveState { saver, .. } => {
-                            let state = serde_json::to_vec(&AppState { uri: format!("foo://bar") }).unwrap();
-                            saver.store(&state);
+                            saver.store_serialized(STATE_VERSION, &AppState { uri: format!("foo://bar") }).unwrap();
                         },
                         MainEvent::Pause => {},
                         MainEvent::Resume { loader, .. } => {
-                            if let Some(state) = loader.load() {
-                                let state: AppState = serde_json::from_slice(&state).unwrap();
-                                info!("Resumed with saved state = {state:#?}");
+                            match loader.load_deserialized::<AppState>(STATE_VERSION) {
+                                Ok(Some(state)) => info!("Resumed with saved state = {state:#?}"),
+                                Ok(None) => {}
+                                Err(err) => warn!("Discarding saved state: {err}"),
                             }
                         },
                         MainEvent::InitWindow { .. } => {
@@ -54,7 +57,7 @@ extern "C" fn android_main() {
                         }
                         MainEvent::WindowResized { .. } => { redraw_pending = true; },
                         MainEvent::RedrawNeeded { ..} => { redraw_pending = true; },
-                        MainEvent::LowMemory => {},
+                        MainEvent::LowMemory { .. } => {},
 
                         MainEvent::Destroy => { quit = true },
                         _ => { /* ... */}
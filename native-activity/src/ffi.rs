@@ -1,34 +0,0 @@
-//! The bindings are pre-generated and the right one for the platform is selected at compile time.
-
-// Bindgen lints
-#![allow(non_upper_case_globals)]
-#![allow(non_camel_case_types)]
-#![allow(non_snake_case)]
-#![allow(improper_ctypes)]
-#![allow(clippy::all)]
-// Temporarily allow UB nullptr dereference in bindgen layout tests until fixed upstream:
-// https://github.com/rust-lang/rust-bindgen/pull/2055
-// https://github.com/rust-lang/rust-bindgen/pull/2064
-#![allow(deref_nullptr)]
-#![allow(dead_code)]
-
-use jni_sys::*;
-use ndk_sys::AAssetManager;
-use ndk_sys::ANativeWindow;
-use ndk_sys::{ALooper, AConfiguration, AInputQueue};
-
-
-#[cfg(all(
-    any(target_os = "android", feature = "test"),
-    any(target_arch = "arm", target_arch = "armv7")
-))]
-include!("ffi_arm.rs");
-
-#[cfg(all(any(target_os = "android", feature = "test"), target_arch = "aarch64"))]
-include!("ffi_aarch64.rs");
-
-#[cfg(all(any(target_os = "android", feature = "test"), target_arch = "x86"))]
-include!("ffi_i686.rs");
-
-#[cfg(all(any(target_os = "android", feature = "test"), target_arch = "x86_64"))]
-include!("ffi_x86_64.rs");
\ No newline at end of file
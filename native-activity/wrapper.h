@@ -1 +0,0 @@
-#include <native-activity/native_app_glue/android_native_app_glue.h>
\ No newline at end of file